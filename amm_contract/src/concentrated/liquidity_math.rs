@@ -0,0 +1,168 @@
+//! Token amounts, price moves and swap steps for liquidity concentrated between two square-root
+//! prices. Each function returns `None` if its result does not fit 256 bits. Rounding always
+//! favours the pool.
+use casper_types::{U256, U512};
+
+use crate::math::{mul_div, mul_div_rounding_up, narrow, widen, BPS_DENOMINATOR};
+
+/// Fixed-point resolution of the square-root prices (Q64.96).
+pub const RESOLUTION: usize = 96;
+
+fn div_rounding_up(a: U256, b: U256) -> U256 {
+    let quotient = a / b;
+    if (a % b).is_zero() {
+        quotient
+    } else {
+        quotient + U256::one()
+    }
+}
+
+fn sorted(x: U256, y: U256) -> (U256, U256) {
+    if x <= y {
+        (x, y)
+    } else {
+        (y, x)
+    }
+}
+
+/// Amount of token A held by `liquidity` between the two square-root prices:
+/// `L * (upper - lower) / (upper * lower)`.
+pub fn amount_a_delta(
+    sqrt_price_x: U256,
+    sqrt_price_y: U256,
+    liquidity: U256,
+    round_up: bool,
+) -> Option<U256> {
+    let (lower, upper) = sorted(sqrt_price_x, sqrt_price_y);
+    if lower.is_zero() {
+        return None;
+    }
+    let numerator = liquidity << RESOLUTION;
+    if round_up {
+        Some(div_rounding_up(
+            mul_div_rounding_up(numerator, upper - lower, upper)?,
+            lower,
+        ))
+    } else {
+        Some(mul_div(numerator, upper - lower, upper)? / lower)
+    }
+}
+
+/// Amount of token B held by `liquidity` between the two square-root prices:
+/// `L * (upper - lower)`.
+pub fn amount_b_delta(
+    sqrt_price_x: U256,
+    sqrt_price_y: U256,
+    liquidity: U256,
+    round_up: bool,
+) -> Option<U256> {
+    let (lower, upper) = sorted(sqrt_price_x, sqrt_price_y);
+    let q96 = U256::one() << RESOLUTION;
+    if round_up {
+        mul_div_rounding_up(liquidity, upper - lower, q96)
+    } else {
+        mul_div(liquidity, upper - lower, q96)
+    }
+}
+
+/// Square-root price after `amount_in` of the input token is added at `sqrt_price`. Selling A
+/// (`a_to_b`) lowers the price and rounds up; selling B raises it and rounds down.
+pub fn next_sqrt_price_from_input(
+    sqrt_price: U256,
+    liquidity: U256,
+    amount_in: U256,
+    a_to_b: bool,
+) -> Option<U256> {
+    if amount_in.is_zero() {
+        return Some(sqrt_price);
+    }
+    if a_to_b {
+        // L * P / (L + amount * P), with L scaled to Q96.
+        let numerator = widen(liquidity << RESOLUTION);
+        let denominator = numerator + widen(amount_in) * widen(sqrt_price);
+        let product = numerator * widen(sqrt_price);
+        let mut quotient = product / denominator;
+        if !(product % denominator).is_zero() {
+            quotient += U512::one();
+        }
+        narrow(quotient)
+    } else {
+        sqrt_price.checked_add(mul_div(amount_in, U256::one() << RESOLUTION, liquidity)?)
+    }
+}
+
+/// Outcome of swapping within a single initialized-tick interval.
+pub struct SwapStep {
+    pub sqrt_price_next: U256,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    pub fee_amount: U256,
+}
+
+/// Swaps up to `amount_remaining` of the input token (fee included) with `liquidity`, moving the
+/// price from `sqrt_price_current` towards, but not past, `sqrt_price_target`.
+pub fn compute_swap_step(
+    sqrt_price_current: U256,
+    sqrt_price_target: U256,
+    liquidity: U256,
+    amount_remaining: U256,
+    fee_bps: u32,
+) -> Option<SwapStep> {
+    let a_to_b = sqrt_price_current >= sqrt_price_target;
+    let fee_bps = U256::from(fee_bps);
+    let denominator = U256::from(BPS_DENOMINATOR);
+
+    let amount_remaining_less_fee = mul_div(amount_remaining, denominator - fee_bps, denominator)?;
+    let amount_to_target = if a_to_b {
+        amount_a_delta(sqrt_price_target, sqrt_price_current, liquidity, true)?
+    } else {
+        amount_b_delta(sqrt_price_current, sqrt_price_target, liquidity, true)?
+    };
+
+    let sqrt_price_next = if amount_remaining_less_fee >= amount_to_target {
+        sqrt_price_target
+    } else {
+        next_sqrt_price_from_input(
+            sqrt_price_current,
+            liquidity,
+            amount_remaining_less_fee,
+            a_to_b,
+        )?
+    };
+    let reached_target = sqrt_price_next == sqrt_price_target;
+
+    let (amount_in, amount_out) = if a_to_b {
+        (
+            if reached_target {
+                amount_to_target
+            } else {
+                amount_a_delta(sqrt_price_next, sqrt_price_current, liquidity, true)?
+            },
+            amount_b_delta(sqrt_price_next, sqrt_price_current, liquidity, false)?,
+        )
+    } else {
+        (
+            if reached_target {
+                amount_to_target
+            } else {
+                amount_b_delta(sqrt_price_current, sqrt_price_next, liquidity, true)?
+            },
+            amount_a_delta(sqrt_price_current, sqrt_price_next, liquidity, false)?,
+        )
+    };
+
+    // Short of the target the whole remainder is spent, so whatever the price move did not use
+    // is fee.
+    let fee_amount = if reached_target {
+        mul_div_rounding_up(amount_in, fee_bps, denominator - fee_bps)?
+    } else {
+        amount_remaining - amount_in
+    };
+
+    Some(SwapStep {
+        sqrt_price_next,
+        amount_in,
+        amount_out,
+        fee_amount,
+    })
+}
@@ -0,0 +1,765 @@
+//! Concentrated-liquidity pools. Liquidity providers open positions over a tick range
+//! `[tick_lower, tick_upper)` and only trade, and only earn fees, while the price is inside it.
+//! Swap fees are tracked as fee growth per unit of liquidity, globally and on the far side of
+//! every initialized tick, so each position's share is settled when its owner touches it.
+//!
+//! A concentrated pool answers the same `swap_a_to_b`, `swap_b_to_a` and `get_pool_info` calls as
+//! a constant-product pool, so the router can route through either kind.
+mod liquidity_math;
+mod tick_math;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::ContractHash,
+    runtime_args, ApiError, CLType, CLTyped, CLValue, Key, U256,
+};
+
+use crate::{
+    ensure_not_expired, ensure_not_paused,
+    events::{self, PoolEvent},
+    factory::hex_encode,
+    get_immediate_caller, get_uref, init_events_mode, lock,
+    math::{mul_div, BPS_DENOMINATOR},
+    read_fee_bps, self_key, unlock, ADMIN, ARG_ADMIN, ARG_FEE_BPS, ARG_PACKAGE_HASH, ARG_RECIPIENT,
+    DEFAULT_FEE_BPS, ERROR_ALREADY_INITIALIZED, ERROR_INSUFFICIENT_INPUT,
+    ERROR_INSUFFICIENT_LIQUIDITY, ERROR_INSUFFICIENT_OUTPUT, ERROR_INVALID_FEE,
+    ERROR_INVALID_PRICE, ERROR_INVALID_TICK, ERROR_MATH_OVERFLOW, FEE_BPS, LOCKED, PACKAGE_HASH,
+    PAUSED, TOKEN_A, TOKEN_B,
+};
+use liquidity_math::{amount_a_delta, amount_b_delta, compute_swap_step};
+use tick_math::{
+    max_sqrt_price, min_sqrt_price, sqrt_price_at_tick, tick_at_sqrt_price, MAX_TICK, MIN_TICK,
+};
+
+const SQRT_PRICE: &str = "sqrt_price_x96";
+const TICK: &str = "tick";
+const LIQUIDITY: &str = "liquidity";
+const FEE_GROWTH_GLOBAL_A: &str = "fee_growth_global_a";
+const FEE_GROWTH_GLOBAL_B: &str = "fee_growth_global_b";
+const TICK_SPACING: &str = "tick_spacing";
+const INITIALIZED_TICKS: &str = "initialized_ticks";
+const TICKS: &str = "ticks";
+const POSITIONS: &str = "positions";
+
+const ARG_TICK_SPACING: &str = "tick_spacing";
+const ARG_SQRT_PRICE: &str = "sqrt_price_x96";
+const ARG_TICK_LOWER: &str = "tick_lower";
+const ARG_TICK_UPPER: &str = "tick_upper";
+const ARG_LIQUIDITY: &str = "liquidity";
+const ARG_OWNER: &str = "owner";
+
+/// Fee growth is accumulated per unit of liquidity in Q128.128.
+fn q128() -> U256 {
+    U256::one() << 128
+}
+
+/// Per-tick state. `liquidity_in`/`liquidity_out` sum the liquidity of the positions whose range
+/// starts/ends at the tick; crossing it upwards adds the first and removes the second. The fee
+/// growth "outside" is the growth on the side of the tick away from the current price.
+#[derive(Clone, Copy, Default)]
+struct TickInfo {
+    liquidity_gross: U256,
+    liquidity_in: U256,
+    liquidity_out: U256,
+    fee_growth_outside_a: U256,
+    fee_growth_outside_b: U256,
+}
+
+impl CLTyped for TickInfo {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for TickInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.liquidity_gross.to_bytes()?);
+        result.append(&mut self.liquidity_in.to_bytes()?);
+        result.append(&mut self.liquidity_out.to_bytes()?);
+        result.append(&mut self.fee_growth_outside_a.to_bytes()?);
+        result.append(&mut self.fee_growth_outside_b.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.liquidity_gross.serialized_length()
+            + self.liquidity_in.serialized_length()
+            + self.liquidity_out.serialized_length()
+            + self.fee_growth_outside_a.serialized_length()
+            + self.fee_growth_outside_b.serialized_length()
+    }
+}
+
+impl FromBytes for TickInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (liquidity_gross, remainder) = U256::from_bytes(bytes)?;
+        let (liquidity_in, remainder) = U256::from_bytes(remainder)?;
+        let (liquidity_out, remainder) = U256::from_bytes(remainder)?;
+        let (fee_growth_outside_a, remainder) = U256::from_bytes(remainder)?;
+        let (fee_growth_outside_b, remainder) = U256::from_bytes(remainder)?;
+        let info = TickInfo {
+            liquidity_gross,
+            liquidity_in,
+            liquidity_out,
+            fee_growth_outside_a,
+            fee_growth_outside_b,
+        };
+        Ok((info, remainder))
+    }
+}
+
+/// A liquidity position of one owner over one tick range, with the fee growth inside the range
+/// when it was last settled and the tokens owed to the owner (settled fees and burned liquidity)
+/// that have not been collected yet.
+#[derive(Clone, Copy, Default)]
+struct Position {
+    liquidity: U256,
+    fee_growth_inside_last_a: U256,
+    fee_growth_inside_last_b: U256,
+    tokens_owed_a: U256,
+    tokens_owed_b: U256,
+}
+
+impl CLTyped for Position {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Position {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.liquidity.to_bytes()?);
+        result.append(&mut self.fee_growth_inside_last_a.to_bytes()?);
+        result.append(&mut self.fee_growth_inside_last_b.to_bytes()?);
+        result.append(&mut self.tokens_owed_a.to_bytes()?);
+        result.append(&mut self.tokens_owed_b.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.liquidity.serialized_length()
+            + self.fee_growth_inside_last_a.serialized_length()
+            + self.fee_growth_inside_last_b.serialized_length()
+            + self.tokens_owed_a.serialized_length()
+            + self.tokens_owed_b.serialized_length()
+    }
+}
+
+impl FromBytes for Position {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (liquidity, remainder) = U256::from_bytes(bytes)?;
+        let (fee_growth_inside_last_a, remainder) = U256::from_bytes(remainder)?;
+        let (fee_growth_inside_last_b, remainder) = U256::from_bytes(remainder)?;
+        let (tokens_owed_a, remainder) = U256::from_bytes(remainder)?;
+        let (tokens_owed_b, remainder) = U256::from_bytes(remainder)?;
+        let position = Position {
+            liquidity,
+            fee_growth_inside_last_a,
+            fee_growth_inside_last_b,
+            tokens_owed_a,
+            tokens_owed_b,
+        };
+        Ok((position, remainder))
+    }
+}
+
+/// Whether the running pool was initialized as a concentrated-liquidity pool.
+pub(crate) fn is_concentrated() -> bool {
+    runtime::has_key(SQRT_PRICE)
+}
+
+fn read<T: CLTyped + FromBytes>(name: &str) -> T {
+    storage::read(get_uref(name))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn checked<T>(value: Option<T>) -> T {
+    value.unwrap_or_revert_with(ApiError::User(ERROR_MATH_OVERFLOW))
+}
+
+fn read_tick(tick: i32) -> TickInfo {
+    storage::dictionary_get(get_uref(TICKS), &tick.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_tick(tick: i32, info: TickInfo) {
+    storage::dictionary_put(get_uref(TICKS), &tick.to_string(), info);
+}
+
+fn position_key(owner: Key, tick_lower: i32, tick_upper: i32) -> String {
+    let mut preimage = owner.to_bytes().unwrap_or_revert();
+    preimage.append(&mut tick_lower.to_bytes().unwrap_or_revert());
+    preimage.append(&mut tick_upper.to_bytes().unwrap_or_revert());
+    hex_encode(&runtime::blake2b(preimage))
+}
+
+fn read_position(key: &str) -> Position {
+    storage::dictionary_get(get_uref(POSITIONS), key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_position(key: &str, position: Position) {
+    storage::dictionary_put(get_uref(POSITIONS), key, position);
+}
+
+/// Reverts unless the range is ordered, within bounds and aligned to the tick spacing.
+fn check_ticks(tick_lower: i32, tick_upper: i32) {
+    let tick_spacing: i32 = read(TICK_SPACING);
+    if tick_lower >= tick_upper
+        || tick_lower < MIN_TICK
+        || tick_upper > MAX_TICK
+        || tick_lower % tick_spacing != 0
+        || tick_upper % tick_spacing != 0
+    {
+        runtime::revert(ApiError::User(ERROR_INVALID_TICK));
+    }
+}
+
+/// Fee growth per unit of liquidity inside `[tick_lower, tick_upper)`, derived from the global
+/// growth and the growth outside each bound. Differences wrap; only changes over time matter.
+fn fee_growth_inside(
+    global: U256,
+    lower_outside: U256,
+    upper_outside: U256,
+    tick_lower: i32,
+    tick_upper: i32,
+    tick: i32,
+) -> U256 {
+    let below = if tick >= tick_lower {
+        lower_outside
+    } else {
+        global.overflowing_sub(lower_outside).0
+    };
+    let above = if tick < tick_upper {
+        upper_outside
+    } else {
+        global.overflowing_sub(upper_outside).0
+    };
+    global.overflowing_sub(below).0.overflowing_sub(above).0
+}
+
+/// Adds `delta` liquidity to, or removes it from, the range bound at `tick`. A tick gaining its
+/// first liquidity is initialized, assuming all growth so far happened below the current price;
+/// a tick losing its last liquidity is cleared.
+fn update_tick(tick: i32, delta: U256, adding: bool, is_upper: bool, current_tick: i32) {
+    let mut info = read_tick(tick);
+    let ticks_uref = get_uref(INITIALIZED_TICKS);
+    let mut initialized: Vec<i32> = storage::read(ticks_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+
+    if adding {
+        if info.liquidity_gross.is_zero() {
+            if tick <= current_tick {
+                info.fee_growth_outside_a = read(FEE_GROWTH_GLOBAL_A);
+                info.fee_growth_outside_b = read(FEE_GROWTH_GLOBAL_B);
+            }
+            if let Err(index) = initialized.binary_search(&tick) {
+                initialized.insert(index, tick);
+                storage::write(ticks_uref, initialized);
+            }
+        }
+        info.liquidity_gross = checked(info.liquidity_gross.checked_add(delta));
+        if info.liquidity_gross > U256::from(u128::MAX) {
+            runtime::revert(ApiError::User(ERROR_MATH_OVERFLOW));
+        }
+        if is_upper {
+            info.liquidity_out += delta;
+        } else {
+            info.liquidity_in += delta;
+        }
+    } else {
+        info.liquidity_gross -= delta;
+        if is_upper {
+            info.liquidity_out -= delta;
+        } else {
+            info.liquidity_in -= delta;
+        }
+        if info.liquidity_gross.is_zero() {
+            info = TickInfo::default();
+            if let Ok(index) = initialized.binary_search(&tick) {
+                initialized.remove(index);
+                storage::write(ticks_uref, initialized);
+            }
+        }
+    }
+
+    write_tick(tick, info);
+}
+
+/// Flips the fee growth outside `tick` as the price crosses it and returns the active liquidity
+/// on the other side.
+fn cross_tick(
+    tick: i32,
+    fee_growth_a: U256,
+    fee_growth_b: U256,
+    liquidity: U256,
+    a_to_b: bool,
+) -> U256 {
+    let mut info = read_tick(tick);
+    info.fee_growth_outside_a = fee_growth_a.overflowing_sub(info.fee_growth_outside_a).0;
+    info.fee_growth_outside_b = fee_growth_b.overflowing_sub(info.fee_growth_outside_b).0;
+    write_tick(tick, info);
+
+    if a_to_b {
+        liquidity + info.liquidity_out - info.liquidity_in
+    } else {
+        liquidity + info.liquidity_in - info.liquidity_out
+    }
+}
+
+/// Token amounts backing `liquidity` over `[tick_lower, tick_upper)` at the current price: only
+/// token A below the range, only token B above it, and both inside it.
+fn amounts_for_liquidity(
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: U256,
+    round_up: bool,
+) -> (U256, U256) {
+    let sqrt_price: U256 = read(SQRT_PRICE);
+    let tick: i32 = read(TICK);
+    let sqrt_price_lower = sqrt_price_at_tick(tick_lower);
+    let sqrt_price_upper = sqrt_price_at_tick(tick_upper);
+
+    if tick < tick_lower {
+        let amount_a = amount_a_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up);
+        (checked(amount_a), U256::zero())
+    } else if tick < tick_upper {
+        let amount_a = amount_a_delta(sqrt_price, sqrt_price_upper, liquidity, round_up);
+        let amount_b = amount_b_delta(sqrt_price_lower, sqrt_price, liquidity, round_up);
+        (checked(amount_a), checked(amount_b))
+    } else {
+        let amount_b = amount_b_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up);
+        (U256::zero(), checked(amount_b))
+    }
+}
+
+/// Adds (`adding`) or removes `delta` liquidity from the `owner`'s position, settling the fees it
+/// earned since it was last touched, and returns the token amounts the change is worth.
+fn modify_position(
+    owner: Key,
+    tick_lower: i32,
+    tick_upper: i32,
+    delta: U256,
+    adding: bool,
+) -> (U256, U256) {
+    check_ticks(tick_lower, tick_upper);
+    let key = position_key(owner, tick_lower, tick_upper);
+    let mut position = read_position(&key);
+    if !adding && delta > position.liquidity {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_LIQUIDITY));
+    }
+
+    let tick: i32 = read(TICK);
+    if adding && !delta.is_zero() {
+        update_tick(tick_lower, delta, true, false, tick);
+        update_tick(tick_upper, delta, true, true, tick);
+    }
+
+    let lower = read_tick(tick_lower);
+    let upper = read_tick(tick_upper);
+    let inside_a = fee_growth_inside(
+        read(FEE_GROWTH_GLOBAL_A),
+        lower.fee_growth_outside_a,
+        upper.fee_growth_outside_a,
+        tick_lower,
+        tick_upper,
+        tick,
+    );
+    let inside_b = fee_growth_inside(
+        read(FEE_GROWTH_GLOBAL_B),
+        lower.fee_growth_outside_b,
+        upper.fee_growth_outside_b,
+        tick_lower,
+        tick_upper,
+        tick,
+    );
+
+    let earned_a = inside_a
+        .overflowing_sub(position.fee_growth_inside_last_a)
+        .0;
+    let earned_b = inside_b
+        .overflowing_sub(position.fee_growth_inside_last_b)
+        .0;
+    position.tokens_owed_a = position.tokens_owed_a.saturating_add(checked(mul_div(
+        position.liquidity,
+        earned_a,
+        q128(),
+    )));
+    position.tokens_owed_b = position.tokens_owed_b.saturating_add(checked(mul_div(
+        position.liquidity,
+        earned_b,
+        q128(),
+    )));
+    position.fee_growth_inside_last_a = inside_a;
+    position.fee_growth_inside_last_b = inside_b;
+
+    if adding {
+        position.liquidity += delta;
+    } else {
+        position.liquidity -= delta;
+        if !delta.is_zero() {
+            update_tick(tick_lower, delta, false, false, tick);
+            update_tick(tick_upper, delta, false, true, tick);
+        }
+    }
+
+    let amounts = amounts_for_liquidity(tick_lower, tick_upper, delta, adding);
+    if !adding {
+        position.tokens_owed_a = position.tokens_owed_a.saturating_add(amounts.0);
+        position.tokens_owed_b = position.tokens_owed_b.saturating_add(amounts.1);
+    }
+    write_position(&key, position);
+
+    if tick_lower <= tick && tick < tick_upper {
+        let liquidity_uref = get_uref(LIQUIDITY);
+        let liquidity: U256 = storage::read(liquidity_uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert();
+        storage::write(
+            liquidity_uref,
+            if adding {
+                liquidity + delta
+            } else {
+                liquidity - delta
+            },
+        );
+    }
+
+    amounts
+}
+
+fn read_tokens() -> (ContractHash, ContractHash) {
+    (read(TOKEN_A), read(TOKEN_B))
+}
+
+/// Closest initialized tick at or below `tick` when the price is falling, or above it when the
+/// price is rising, falling back to the end of the tick range.
+fn next_initialized_tick(initialized: &[i32], tick: i32, a_to_b: bool) -> i32 {
+    if a_to_b {
+        let index = initialized.partition_point(|&t| t <= tick);
+        if index == 0 {
+            MIN_TICK
+        } else {
+            initialized[index - 1]
+        }
+    } else {
+        let index = initialized.partition_point(|&t| t <= tick);
+        initialized.get(index).copied().unwrap_or(MAX_TICK)
+    }
+}
+
+/// Swaps an exact `amount_in` tick range by tick range until it is spent or the pool runs out of
+/// liquidity, in which case only the part that was swapped is pulled from the caller. Pays the
+/// output to the optional `recipient` (the caller by default) and returns it.
+pub(crate) fn swap_exact_input(a_to_b: bool, amount_in: U256, min_out: U256) -> U256 {
+    let fee_bps = read_fee_bps();
+    let initialized: Vec<i32> = read(INITIALIZED_TICKS);
+    let mut sqrt_price: U256 = read(SQRT_PRICE);
+    let mut tick: i32 = read(TICK);
+    let mut liquidity: U256 = read(LIQUIDITY);
+    let mut fee_growth_a: U256 = read(FEE_GROWTH_GLOBAL_A);
+    let mut fee_growth_b: U256 = read(FEE_GROWTH_GLOBAL_B);
+
+    let price_limit = if a_to_b {
+        min_sqrt_price() + U256::one()
+    } else {
+        max_sqrt_price() - U256::one()
+    };
+
+    let mut amount_remaining = amount_in;
+    let mut amount_out = U256::zero();
+    while !amount_remaining.is_zero() && sqrt_price != price_limit {
+        let next_tick = next_initialized_tick(&initialized, tick, a_to_b);
+        let sqrt_price_next_tick = sqrt_price_at_tick(next_tick);
+        let target = if a_to_b {
+            sqrt_price_next_tick.max(price_limit)
+        } else {
+            sqrt_price_next_tick.min(price_limit)
+        };
+
+        let step = checked(compute_swap_step(
+            sqrt_price,
+            target,
+            liquidity,
+            amount_remaining,
+            fee_bps,
+        ));
+        amount_remaining = amount_remaining.saturating_sub(step.amount_in + step.fee_amount);
+        amount_out += step.amount_out;
+
+        if !liquidity.is_zero() {
+            let growth = checked(mul_div(step.fee_amount, q128(), liquidity));
+            if a_to_b {
+                fee_growth_a = fee_growth_a.overflowing_add(growth).0;
+            } else {
+                fee_growth_b = fee_growth_b.overflowing_add(growth).0;
+            }
+        }
+
+        sqrt_price = step.sqrt_price_next;
+        if sqrt_price == sqrt_price_next_tick {
+            if initialized.binary_search(&next_tick).is_ok() {
+                liquidity = cross_tick(next_tick, fee_growth_a, fee_growth_b, liquidity, a_to_b);
+            }
+            tick = if a_to_b { next_tick - 1 } else { next_tick };
+        } else {
+            tick = tick_at_sqrt_price(sqrt_price);
+        }
+    }
+
+    let amount_in_used = amount_in - amount_remaining;
+    if amount_in_used.is_zero() || amount_out.is_zero() {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_LIQUIDITY));
+    }
+    if amount_out < min_out {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_OUTPUT));
+    }
+
+    storage::write(get_uref(SQRT_PRICE), sqrt_price);
+    storage::write(get_uref(TICK), tick);
+    storage::write(get_uref(LIQUIDITY), liquidity);
+    storage::write(get_uref(FEE_GROWTH_GLOBAL_A), fee_growth_a);
+    storage::write(get_uref(FEE_GROWTH_GLOBAL_B), fee_growth_b);
+
+    let (token_a, token_b) = read_tokens();
+    let (token_in, token_out) = if a_to_b {
+        (token_a, token_b)
+    } else {
+        (token_b, token_a)
+    };
+    let trader = get_immediate_caller();
+    let recipient: Key = runtime::try_get_named_arg(ARG_RECIPIENT).unwrap_or(trader);
+
+    let (amount_a_in, amount_b_in, amount_a_out, amount_b_out) = if a_to_b {
+        (amount_in_used, U256::zero(), U256::zero(), amount_out)
+    } else {
+        (U256::zero(), amount_in_used, amount_out, U256::zero())
+    };
+    events::emit_event(PoolEvent::Swap(events::Swap {
+        sender: trader,
+        amount_a_in,
+        amount_b_in,
+        amount_a_out,
+        amount_b_out,
+        recipient,
+    }));
+
+    runtime::call_contract::<()>(
+        token_in,
+        "transfer_from",
+        runtime_args! {
+            "owner" => trader,
+            "recipient" => self_key(),
+            "amount" => amount_in_used,
+        },
+    );
+    runtime::call_contract::<()>(
+        token_out,
+        "transfer",
+        runtime_args! {
+            "recipient" => recipient,
+            "amount" => amount_out,
+        },
+    );
+
+    amount_out
+}
+
+/// Initializes a concentrated-liquidity pool at the `sqrt_price_x96` starting price
+/// (`sqrt(price of A in B) * 2^96`), with positions aligned to `tick_spacing`.
+#[no_mangle]
+pub extern "C" fn init_concentrated() {
+    if runtime::has_key(TOKEN_A) {
+        runtime::revert(ApiError::User(ERROR_ALREADY_INITIALIZED));
+    }
+    let token_a: ContractHash = runtime::get_named_arg("token_a");
+    let token_b: ContractHash = runtime::get_named_arg("token_b");
+    let fee_bps: u32 = runtime::try_get_named_arg(ARG_FEE_BPS).unwrap_or(DEFAULT_FEE_BPS);
+    if fee_bps >= BPS_DENOMINATOR {
+        runtime::revert(ApiError::User(ERROR_INVALID_FEE));
+    }
+    let tick_spacing: u32 = runtime::get_named_arg(ARG_TICK_SPACING);
+    if tick_spacing == 0 || tick_spacing > MAX_TICK as u32 {
+        runtime::revert(ApiError::User(ERROR_INVALID_TICK));
+    }
+    let sqrt_price: U256 = runtime::get_named_arg(ARG_SQRT_PRICE);
+    if sqrt_price < min_sqrt_price() || sqrt_price >= max_sqrt_price() {
+        runtime::revert(ApiError::User(ERROR_INVALID_PRICE));
+    }
+    let admin: Key =
+        runtime::try_get_named_arg(ARG_ADMIN).unwrap_or_else(|| Key::from(runtime::get_caller()));
+
+    runtime::put_key(TOKEN_A, storage::new_uref(token_a).into());
+    runtime::put_key(TOKEN_B, storage::new_uref(token_b).into());
+    runtime::put_key(ADMIN, storage::new_uref(admin).into());
+    runtime::put_key(FEE_BPS, storage::new_uref(fee_bps).into());
+    runtime::put_key(LOCKED, storage::new_uref(false).into());
+    runtime::put_key(PAUSED, storage::new_uref(false).into());
+    runtime::put_key(SQRT_PRICE, storage::new_uref(sqrt_price).into());
+    runtime::put_key(
+        TICK,
+        storage::new_uref(tick_at_sqrt_price(sqrt_price)).into(),
+    );
+    runtime::put_key(LIQUIDITY, storage::new_uref(U256::zero()).into());
+    runtime::put_key(FEE_GROWTH_GLOBAL_A, storage::new_uref(U256::zero()).into());
+    runtime::put_key(FEE_GROWTH_GLOBAL_B, storage::new_uref(U256::zero()).into());
+    runtime::put_key(TICK_SPACING, storage::new_uref(tick_spacing as i32).into());
+    runtime::put_key(
+        INITIALIZED_TICKS,
+        storage::new_uref(Vec::<i32>::new()).into(),
+    );
+    storage::new_dictionary(TICKS).unwrap_or_revert();
+    storage::new_dictionary(POSITIONS).unwrap_or_revert();
+
+    if let Some(package_hash) = runtime::try_get_named_arg::<Key>(ARG_PACKAGE_HASH) {
+        runtime::put_key(PACKAGE_HASH, package_hash);
+    }
+    init_events_mode();
+}
+
+/// Adds `liquidity` to the caller's position over `[tick_lower, tick_upper)`, pulling the token
+/// amounts it takes at the current price from the caller. Returns `(amount_a, amount_b)`.
+#[no_mangle]
+pub extern "C" fn mint_position() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
+    let tick_lower: i32 = runtime::get_named_arg(ARG_TICK_LOWER);
+    let tick_upper: i32 = runtime::get_named_arg(ARG_TICK_UPPER);
+    let liquidity: U256 = runtime::get_named_arg(ARG_LIQUIDITY);
+    if liquidity.is_zero() {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_INPUT));
+    }
+
+    let owner = get_immediate_caller();
+    let (amount_a, amount_b) = modify_position(owner, tick_lower, tick_upper, liquidity, true);
+    events::emit_event(PoolEvent::LiquidityAdded(events::LiquidityAdded {
+        provider: owner,
+        amount_a,
+        amount_b,
+    }));
+
+    let (token_a, token_b) = read_tokens();
+    let pool = self_key();
+    for (token, amount) in [(token_a, amount_a), (token_b, amount_b)] {
+        if !amount.is_zero() {
+            runtime::call_contract::<()>(
+                token,
+                "transfer_from",
+                runtime_args! {
+                    "owner" => owner,
+                    "recipient" => pool,
+                    "amount" => amount,
+                },
+            );
+        }
+    }
+
+    unlock();
+    runtime::ret(CLValue::from_t((amount_a, amount_b)).unwrap_or_revert());
+}
+
+/// Removes `liquidity` from the caller's position and credits the tokens it was worth, plus the
+/// fees earned so far, to the position for [`collect`]. Burning zero only settles the fees.
+/// Returns the `(amount_a, amount_b)` the removed liquidity was worth. Like [`collect`], it stays
+/// open while the pool is paused so providers can always exit.
+#[no_mangle]
+pub extern "C" fn burn_position() {
+    lock();
+    ensure_not_expired();
+
+    let tick_lower: i32 = runtime::get_named_arg(ARG_TICK_LOWER);
+    let tick_upper: i32 = runtime::get_named_arg(ARG_TICK_UPPER);
+    let liquidity: U256 = runtime::get_named_arg(ARG_LIQUIDITY);
+
+    let owner = get_immediate_caller();
+    let amounts = modify_position(owner, tick_lower, tick_upper, liquidity, false);
+    if !liquidity.is_zero() {
+        events::emit_event(PoolEvent::LiquidityRemoved(events::LiquidityRemoved {
+            provider: owner,
+            amount_a: amounts.0,
+            amount_b: amounts.1,
+        }));
+    }
+
+    unlock();
+    runtime::ret(CLValue::from_t(amounts).unwrap_or_revert());
+}
+
+/// Sends everything owed to the caller's position to the optional `recipient` (the caller by
+/// default) and returns the `(amount_a, amount_b)` sent. Not blocked by a pause.
+#[no_mangle]
+pub extern "C" fn collect() {
+    lock();
+    ensure_not_expired();
+
+    let tick_lower: i32 = runtime::get_named_arg(ARG_TICK_LOWER);
+    let tick_upper: i32 = runtime::get_named_arg(ARG_TICK_UPPER);
+    let owner = get_immediate_caller();
+    let recipient: Key = runtime::try_get_named_arg(ARG_RECIPIENT).unwrap_or(owner);
+
+    let key = position_key(owner, tick_lower, tick_upper);
+    let mut position = read_position(&key);
+    let owed = (position.tokens_owed_a, position.tokens_owed_b);
+    position.tokens_owed_a = U256::zero();
+    position.tokens_owed_b = U256::zero();
+    write_position(&key, position);
+
+    let (token_a, token_b) = read_tokens();
+    for (token, amount) in [(token_a, owed.0), (token_b, owed.1)] {
+        if !amount.is_zero() {
+            runtime::call_contract::<()>(
+                token,
+                "transfer",
+                runtime_args! {
+                    "recipient" => recipient,
+                    "amount" => amount,
+                },
+            );
+        }
+    }
+
+    unlock();
+    runtime::ret(CLValue::from_t(owed).unwrap_or_revert());
+}
+
+/// Returns `(liquidity, tokens_owed_a, tokens_owed_b)` of `owner`'s position over the range.
+/// Fees earned since the position was last touched are not included until it is burned or
+/// settled with a zero burn.
+#[no_mangle]
+pub extern "C" fn get_position() {
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    let tick_lower: i32 = runtime::get_named_arg(ARG_TICK_LOWER);
+    let tick_upper: i32 = runtime::get_named_arg(ARG_TICK_UPPER);
+
+    let position = read_position(&position_key(owner, tick_lower, tick_upper));
+    let info = (
+        position.liquidity,
+        position.tokens_owed_a,
+        position.tokens_owed_b,
+    );
+    runtime::ret(CLValue::from_t(info).unwrap_or_revert());
+}
+
+/// Returns `(sqrt_price_x96, tick, liquidity)`: the current price, its tick and the liquidity
+/// active at it.
+#[no_mangle]
+pub extern "C" fn get_slot() {
+    let sqrt_price: U256 = read(SQRT_PRICE);
+    let tick: i32 = read(TICK);
+    let liquidity: U256 = read(LIQUIDITY);
+    runtime::ret(CLValue::from_t((sqrt_price, tick, liquidity)).unwrap_or_revert());
+}
@@ -0,0 +1,85 @@
+//! Conversions between ticks and square-root prices. A tick `i` stands for the price `1.0001^i`
+//! of token A in token B; prices are carried as `sqrt(price) * 2^96` so the liquidity math stays
+//! in integers.
+use casper_types::U256;
+
+/// Lowest tick whose square-root price fits the Q64.96 range.
+pub const MIN_TICK: i32 = -887_272;
+/// Highest tick whose square-root price fits the Q64.96 range.
+pub const MAX_TICK: i32 = 887_272;
+
+/// `2^128 / sqrt(1.0001^(2^i))` for each bit `i` of the absolute tick, in Q128.128.
+const RATIOS: [(u32, u128); 19] = [
+    (0x2, 0xfff97272373d413259a46990580e213a),
+    (0x4, 0xfff2e50f5f656932ef12357cf3c7fdcc),
+    (0x8, 0xffe5caca7e10e4e61c3624eaa0941cd0),
+    (0x10, 0xffcb9843d60f6159c9db58835c926644),
+    (0x20, 0xff973b41fa98c081472e6896dfb254c0),
+    (0x40, 0xff2ea16466c96a3843ec78b326b52861),
+    (0x80, 0xfe5dee046a99a2a811c461f1969c3053),
+    (0x100, 0xfcbe86c7900a88aedcffc83b479aa3a4),
+    (0x200, 0xf987a7253ac413176f2b074cf7815e54),
+    (0x400, 0xf3392b0822b70005940c7a398e4b70f3),
+    (0x800, 0xe7159475a2c29b7443b29c7fa6e889d9),
+    (0x1000, 0xd097f3bdfd2022b8845ad8f792aa5825),
+    (0x2000, 0xa9f746462d870fdf8a65dc1f90e061e5),
+    (0x4000, 0x70d869a156d2a1b890bb3df62baf32f7),
+    (0x8000, 0x31be135f97d08fd981231505542fcfa6),
+    (0x10000, 0x9aa508b5b7a84e1c677de54f3e99bc9),
+    (0x20000, 0x5d6af8dedb81196699c329225ee604),
+    (0x40000, 0x2216e584f5fa1ea926041bedfe98),
+    (0x80000, 0x48a170391f7dc42444e8fa2),
+];
+
+/// Square-root price at `tick`, as `sqrt(1.0001^tick) * 2^96` rounded up. `tick` must lie within
+/// [`MIN_TICK`, `MAX_TICK`].
+pub fn sqrt_price_at_tick(tick: i32) -> U256 {
+    let abs_tick = tick.unsigned_abs();
+
+    let mut ratio = if abs_tick & 0x1 != 0 {
+        U256::from(0xfffcb933bd6fad37aa2d162d1a594001u128)
+    } else {
+        U256::one() << 128
+    };
+    for (bit, multiplier) in RATIOS {
+        if abs_tick & bit != 0 {
+            ratio = (ratio * U256::from(multiplier)) >> 128;
+        }
+    }
+    if tick > 0 {
+        ratio = U256::MAX / ratio;
+    }
+
+    // Q128.128 to Q64.96, rounding up so the tick of the result is `tick` again.
+    let sqrt_price = ratio >> 32;
+    if (ratio & U256::from(u32::MAX)).is_zero() {
+        sqrt_price
+    } else {
+        sqrt_price + U256::one()
+    }
+}
+
+/// Smallest square-root price a pool can reach.
+pub fn min_sqrt_price() -> U256 {
+    sqrt_price_at_tick(MIN_TICK)
+}
+
+/// Largest square-root price a pool can reach.
+pub fn max_sqrt_price() -> U256 {
+    sqrt_price_at_tick(MAX_TICK)
+}
+
+/// Greatest tick whose square-root price is at most `sqrt_price`, found by bisection.
+/// `sqrt_price` must lie within [`min_sqrt_price`, `max_sqrt_price`].
+pub fn tick_at_sqrt_price(sqrt_price: U256) -> i32 {
+    let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        if sqrt_price_at_tick(mid) <= sqrt_price {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    low
+}
@@ -0,0 +1,572 @@
+//! Entry point definitions for the pool and the factory that deploys it.
+use alloc::{boxed::Box, string::String, vec};
+use casper_types::{
+    bytesrepr::Bytes, CLType, CLTyped, EntityEntryPoint as EntryPoint, EntryPointAccess,
+    EntryPointPayment, EntryPointType, EntryPoints, Key, Parameter, URef, U256,
+};
+
+/// Returns the pool entry points with the given access. The factory installs them as
+/// [`EntryPointAccess::Template`] and each pool it deploys exposes them as public.
+pub fn pool_entry_points(access: EntryPointAccess) -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("init"),
+        vec![
+            Parameter::new("token_a", Key::cl_type()),
+            Parameter::new("token_b", Key::cl_type()),
+            // Optional: "fee_bps" : u32, "fee_recipient" : Key, "admin" : Key,
+            // "package_hash" : Key, "amp" : u64, "events_mode" : u8, "wcspr" : Key
+        ],
+        CLType::Unit,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("add_liquidity"),
+        vec![
+            Parameter::new("amount_a", U256::cl_type()),
+            Parameter::new("amount_b", U256::cl_type()),
+            // Optional: "deadline" : u64
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("remove_liquidity"),
+        vec![
+            Parameter::new("shares", U256::cl_type()),
+            // Optional: "deadline" : u64
+        ],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("swap_a_to_b"),
+        vec![
+            Parameter::new("amount_in", U256::cl_type()),
+            Parameter::new("min_amount_out", U256::cl_type()),
+            // Optional: "deadline" : u64, "recipient" : Key
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("swap_b_to_a"),
+        vec![
+            Parameter::new("amount_in", U256::cl_type()),
+            Parameter::new("min_amount_out", U256::cl_type()),
+            // Optional: "deadline" : u64, "recipient" : Key
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("swap_a_for_exact_b"),
+        vec![
+            Parameter::new("amount_out", U256::cl_type()),
+            Parameter::new("max_amount_in", U256::cl_type()),
+            // Optional: "deadline" : u64, "recipient" : Key
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("swap_b_for_exact_a"),
+        vec![
+            Parameter::new("amount_out", U256::cl_type()),
+            Parameter::new("max_amount_in", U256::cl_type()),
+            // Optional: "deadline" : u64, "recipient" : Key
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("swap_cspr_for_tokens"),
+        vec![
+            Parameter::new("amount_in", U256::cl_type()),
+            Parameter::new("min_amount_out", U256::cl_type()),
+            Parameter::new("purse", URef::cl_type()),
+            // Optional: "deadline" : u64, "recipient" : Key
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("swap_tokens_for_cspr"),
+        vec![
+            Parameter::new("amount_in", U256::cl_type()),
+            Parameter::new("min_amount_out", U256::cl_type()),
+            Parameter::new("purse", URef::cl_type()),
+            // Optional: "deadline" : u64
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("flash_swap"),
+        vec![
+            Parameter::new("amount_a_out", U256::cl_type()),
+            Parameter::new("amount_b_out", U256::cl_type()),
+            Parameter::new("callee", Key::cl_type()),
+            Parameter::new("data", Bytes::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            // Optional: "deadline" : u64
+        ],
+        CLType::Unit,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    for name in ["pause", "unpause"] {
+        entry_points.add_entry_point(EntryPoint::new(
+            String::from(name),
+            vec![],
+            CLType::Unit,
+            access.clone(),
+            EntryPointType::Called,
+            EntryPointPayment::Caller,
+        ));
+    }
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("change_events_mode"),
+        vec![Parameter::new("events_mode", u8::cl_type())],
+        CLType::Unit,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("set_protocol_fee"),
+        vec![Parameter::new("protocol_fee_share", u32::cl_type())],
+        CLType::Unit,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("set_fee_recipient"),
+        vec![Parameter::new("fee_recipient", Key::cl_type())],
+        CLType::Unit,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("collect_protocol_fees"),
+        vec![
+            // Optional: "deadline" : u64
+        ],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("sync"),
+        vec![
+            // Optional: "deadline" : u64
+        ],
+        CLType::Unit,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("skim"),
+        vec![
+            Parameter::new("to", Key::cl_type()),
+            // Optional: "deadline" : u64
+        ],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_amount_out"),
+        vec![
+            Parameter::new("amount_in", U256::cl_type()),
+            Parameter::new("a_to_b", CLType::Bool),
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_amount_in"),
+        vec![
+            Parameter::new("amount_out", U256::cl_type()),
+            Parameter::new("a_to_b", CLType::Bool),
+        ],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_spot_price"),
+        vec![],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("quote_add_liquidity"),
+        vec![
+            Parameter::new("amount_a", U256::cl_type()),
+            Parameter::new("amount_b", U256::cl_type()),
+        ],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_pool_info"),
+        vec![],
+        CLType::Tuple3([
+            Box::new(Key::cl_type()),
+            Box::new(Key::cl_type()),
+            Box::new(Key::cl_type()),
+        ]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_user_shares"),
+        vec![Parameter::new("user", Key::cl_type())],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_total_shares"),
+        vec![],
+        U256::cl_type(),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_cumulative_prices"),
+        vec![],
+        CLType::Tuple3([
+            Box::new(U256::cl_type()),
+            Box::new(U256::cl_type()),
+            Box::new(CLType::U64),
+        ]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("consult"),
+        vec![Parameter::new("window", CLType::U64)],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("is_paused"),
+        vec![],
+        CLType::Bool,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_reserves"),
+        vec![],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points
+}
+
+/// Returns the entry points of a concentrated-liquidity pool with the given access. Swaps and
+/// `get_pool_info` keep the constant-product signatures so the router works with either kind.
+pub fn concentrated_pool_entry_points(access: EntryPointAccess) -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("init_concentrated"),
+        vec![
+            Parameter::new("token_a", Key::cl_type()),
+            Parameter::new("token_b", Key::cl_type()),
+            Parameter::new("tick_spacing", u32::cl_type()),
+            Parameter::new("sqrt_price_x96", U256::cl_type()),
+            // Optional: "fee_bps" : u32, "admin" : Key, "package_hash" : Key,
+            // "events_mode" : u8
+        ],
+        CLType::Unit,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("mint_position"),
+        vec![
+            Parameter::new("tick_lower", i32::cl_type()),
+            Parameter::new("tick_upper", i32::cl_type()),
+            Parameter::new("liquidity", U256::cl_type()),
+            // Optional: "deadline" : u64
+        ],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("burn_position"),
+        vec![
+            Parameter::new("tick_lower", i32::cl_type()),
+            Parameter::new("tick_upper", i32::cl_type()),
+            Parameter::new("liquidity", U256::cl_type()),
+            // Optional: "deadline" : u64
+        ],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("collect"),
+        vec![
+            Parameter::new("tick_lower", i32::cl_type()),
+            Parameter::new("tick_upper", i32::cl_type()),
+            // Optional: "deadline" : u64, "recipient" : Key
+        ],
+        CLType::Tuple2([Box::new(U256::cl_type()), Box::new(U256::cl_type())]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    for name in ["swap_a_to_b", "swap_b_to_a"] {
+        entry_points.add_entry_point(EntryPoint::new(
+            String::from(name),
+            vec![
+                Parameter::new("amount_in", U256::cl_type()),
+                Parameter::new("min_amount_out", U256::cl_type()),
+                // Optional: "deadline" : u64, "recipient" : Key
+            ],
+            U256::cl_type(),
+            access.clone(),
+            EntryPointType::Called,
+            EntryPointPayment::Caller,
+        ));
+    }
+
+    for name in ["pause", "unpause"] {
+        entry_points.add_entry_point(EntryPoint::new(
+            String::from(name),
+            vec![],
+            CLType::Unit,
+            access.clone(),
+            EntryPointType::Called,
+            EntryPointPayment::Caller,
+        ));
+    }
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("change_events_mode"),
+        vec![Parameter::new("events_mode", u8::cl_type())],
+        CLType::Unit,
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_position"),
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("tick_lower", i32::cl_type()),
+            Parameter::new("tick_upper", i32::cl_type()),
+        ],
+        CLType::Tuple3([
+            Box::new(U256::cl_type()),
+            Box::new(U256::cl_type()),
+            Box::new(U256::cl_type()),
+        ]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_slot"),
+        vec![],
+        CLType::Tuple3([
+            Box::new(U256::cl_type()),
+            Box::new(i32::cl_type()),
+            Box::new(U256::cl_type()),
+        ]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_pool_info"),
+        vec![],
+        CLType::Tuple3([
+            Box::new(Key::cl_type()),
+            Box::new(Key::cl_type()),
+            Box::new(Key::cl_type()),
+        ]),
+        access.clone(),
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("is_paused"),
+        vec![],
+        CLType::Bool,
+        access,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points
+}
+
+/// Returns the factory's own entry points, on top of the pool template.
+pub fn factory_entry_points() -> EntryPoints {
+    let mut entry_points = pool_entry_points(EntryPointAccess::Template);
+    for entry_point in
+        concentrated_pool_entry_points(EntryPointAccess::Template).take_entry_points()
+    {
+        entry_points.add_entry_point(entry_point);
+    }
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("init_factory"),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("create_pool"),
+        vec![
+            Parameter::new("token_a", Key::cl_type()),
+            Parameter::new("token_b", Key::cl_type()),
+            // Optional: "fee_bps" : u32, "amp" : u64, "events_mode" : u8, "wcspr" : Key
+        ],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Factory,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("create_concentrated_pool"),
+        vec![
+            Parameter::new("token_a", Key::cl_type()),
+            Parameter::new("token_b", Key::cl_type()),
+            Parameter::new("tick_spacing", u32::cl_type()),
+            Parameter::new("sqrt_price_x96", U256::cl_type()),
+            // Optional: "fee_bps" : u32, "events_mode" : u8
+        ],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Factory,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("register_pool"),
+        vec![
+            Parameter::new("token_a", Key::cl_type()),
+            Parameter::new("token_b", Key::cl_type()),
+            Parameter::new("pool", Key::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("get_pool"),
+        vec![
+            Parameter::new("token_x", Key::cl_type()),
+            Parameter::new("token_y", Key::cl_type()),
+        ],
+        CLType::Option(Box::new(Key::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("all_pools"),
+        vec![],
+        CLType::List(Box::new(Key::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points
+}
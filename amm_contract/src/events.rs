@@ -0,0 +1,153 @@
+//! Pool events. Each pool emits them in its own events mode, the same modes as the CEP-18 tokens:
+//! Casper Event Standard events, or JSON native messages (`{"Swap":{...}}`) on the `events`
+//! message topic, as a string or as bytes.
+use alloc::string::String;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_event_standard::{emit, init, Event, Schemas, EVENTS_DICT};
+use casper_types::{bytesrepr::Bytes, contract_messages::MessagePayload, ApiError, Key, U256};
+use serde::Serialize;
+
+use crate::{get_uref, ERROR_INVALID_EVENTS_MODE, EVENTS_TOPIC};
+
+pub(crate) const EVENTS_MODE: &str = "events_mode";
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub(crate) enum EventsMode {
+    NoEvents = 0,
+    CES = 1,
+    Native = 2,
+    NativeBytes = 3,
+}
+
+impl TryFrom<u8> for EventsMode {
+    type Error = ApiError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(EventsMode::NoEvents),
+            1 => Ok(EventsMode::CES),
+            2 => Ok(EventsMode::Native),
+            3 => Ok(EventsMode::NativeBytes),
+            _ => Err(ApiError::User(ERROR_INVALID_EVENTS_MODE)),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) enum PoolEvent {
+    Swap(Swap),
+    LiquidityAdded(LiquidityAdded),
+    LiquidityRemoved(LiquidityRemoved),
+    Sync(Sync),
+    Paused(Paused),
+    Unpaused(Unpaused),
+    ChangeEventsMode(ChangeEventsMode),
+}
+
+/// A swap, from `sender` (who paid the input) to `recipient`. Exactly one side has an input.
+#[derive(Serialize, Event)]
+pub(crate) struct Swap {
+    pub sender: Key,
+    pub amount_a_in: U256,
+    pub amount_b_in: U256,
+    pub amount_a_out: U256,
+    pub amount_b_out: U256,
+    pub recipient: Key,
+}
+
+#[derive(Serialize, Event)]
+pub(crate) struct LiquidityAdded {
+    pub provider: Key,
+    pub amount_a: U256,
+    pub amount_b: U256,
+}
+
+#[derive(Serialize, Event)]
+pub(crate) struct LiquidityRemoved {
+    pub provider: Key,
+    pub amount_a: U256,
+    pub amount_b: U256,
+}
+
+/// The reserves after any change to them.
+#[derive(Serialize, Event)]
+pub(crate) struct Sync {
+    pub reserve_a: U256,
+    pub reserve_b: U256,
+}
+
+#[derive(Serialize, Event)]
+pub(crate) struct Paused {
+    pub admin: Key,
+}
+
+#[derive(Serialize, Event)]
+pub(crate) struct Unpaused {
+    pub admin: Key,
+}
+
+#[derive(Serialize, Event)]
+pub(crate) struct ChangeEventsMode {
+    pub events_mode: u8,
+}
+
+/// The pool's events mode. Pools created before events modes existed emit native messages.
+fn read_events_mode() -> EventsMode {
+    if !runtime::has_key(EVENTS_MODE) {
+        return EventsMode::Native;
+    }
+    let events_mode: u8 = storage::read(get_uref(EVENTS_MODE))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    EventsMode::try_from(events_mode).unwrap_or_revert()
+}
+
+fn to_json(event: &PoolEvent) -> String {
+    serde_json::to_string(event).unwrap_or_revert_with(ApiError::User(ERROR_INVALID_EVENTS_MODE))
+}
+
+pub(crate) fn emit_event(event: PoolEvent) {
+    match read_events_mode() {
+        EventsMode::NoEvents => {}
+        EventsMode::CES => ces(event),
+        EventsMode::Native => {
+            runtime::emit_message(EVENTS_TOPIC, &to_json(&event).into()).unwrap_or_revert()
+        }
+        EventsMode::NativeBytes => {
+            let payload = MessagePayload::Bytes(Bytes::from(to_json(&event).as_bytes()));
+            runtime::emit_message(EVENTS_TOPIC, &payload).unwrap_or_revert()
+        }
+    }
+}
+
+fn ces(event: PoolEvent) {
+    match event {
+        PoolEvent::Swap(ev) => emit(ev),
+        PoolEvent::LiquidityAdded(ev) => emit(ev),
+        PoolEvent::LiquidityRemoved(ev) => emit(ev),
+        PoolEvent::Sync(ev) => emit(ev),
+        PoolEvent::Paused(ev) => emit(ev),
+        PoolEvent::Unpaused(ev) => emit(ev),
+        PoolEvent::ChangeEventsMode(ev) => emit(ev),
+    }
+}
+
+/// Registers the CES schemas the first time the pool is in CES mode.
+pub(crate) fn init_events() {
+    if read_events_mode() == EventsMode::CES && runtime::get_key(EVENTS_DICT).is_none() {
+        let schemas = Schemas::new()
+            .with::<Swap>()
+            .with::<LiquidityAdded>()
+            .with::<LiquidityRemoved>()
+            .with::<Sync>()
+            .with::<Paused>()
+            .with::<Unpaused>()
+            .with::<ChangeEventsMode>();
+        init(schemas);
+    }
+}
@@ -0,0 +1,242 @@
+//! Factory entry points: deploys pools from the template entry points and keeps a registry of
+//! one pool per token pair.
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{
+    bytesrepr::ToBytes, contract_messages::MessageTopicOperation, contracts::ContractHash,
+    runtime_args, ApiError, CLValue, EntryPointAccess, EntryPoints, Key, RuntimeArgs, U256,
+};
+
+use crate::{
+    ensure_admin,
+    entry_points::{concentrated_pool_entry_points, pool_entry_points},
+    get_uref, ADMIN, ERROR_ALREADY_INITIALIZED, ERROR_IDENTICAL_TOKENS, ERROR_INVALID_TOKEN,
+    ERROR_POOL_EXISTS, EVENTS_TOPIC,
+};
+
+const POOLS: &str = "pools";
+const ALL_POOLS: &str = "all_pools";
+pub(crate) const POOL_COUNT: &str = "pool_count";
+
+fn to_contract_hash(token: Key) -> ContractHash {
+    token
+        .into_hash_addr()
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(ApiError::User(ERROR_INVALID_TOKEN))
+}
+
+/// Dictionary item key for a token pair, independent of argument order.
+fn pair_key(token_x: ContractHash, token_y: ContractHash) -> String {
+    if token_x == token_y {
+        runtime::revert(ApiError::User(ERROR_IDENTICAL_TOKENS));
+    }
+    let (low, high) = if token_x < token_y {
+        (token_x, token_y)
+    } else {
+        (token_y, token_x)
+    };
+    let mut preimage = low.to_bytes().unwrap_or_revert();
+    preimage.append(&mut high.to_bytes().unwrap_or_revert());
+    hex_encode(&runtime::blake2b(preimage))
+}
+
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+    let mut s = String::new();
+    for byte in bytes {
+        s.push_str(&format!("{:02x}", byte));
+    }
+    s
+}
+
+/// Stores `pool` under the pair, reverting if the pair already has a pool.
+fn register(token_a: ContractHash, token_b: ContractHash, pool: Key) {
+    let pair = pair_key(token_a, token_b);
+    let pools = get_uref(POOLS);
+    let existing: Option<Key> = storage::dictionary_get(pools, &pair).unwrap_or_revert();
+    if existing.is_some() {
+        runtime::revert(ApiError::User(ERROR_POOL_EXISTS));
+    }
+    storage::dictionary_put(pools, &pair, pool);
+
+    let count_uref = get_uref(POOL_COUNT);
+    let count: u64 = storage::read(count_uref).unwrap_or_revert().unwrap_or(0);
+    storage::dictionary_put(get_uref(ALL_POOLS), &count.to_string(), pool);
+    storage::write(count_uref, count + 1);
+}
+
+#[no_mangle]
+pub extern "C" fn init_factory() {
+    if runtime::has_key(POOLS) {
+        runtime::revert(ApiError::User(ERROR_ALREADY_INITIALIZED));
+    }
+    storage::new_dictionary(POOLS).unwrap_or_revert();
+    storage::new_dictionary(ALL_POOLS).unwrap_or_revert();
+}
+
+/// Deploys a pool contract exposing `entry_points`, registers it for the pair and calls
+/// `init_entry_point` on it with the pair, the factory admin as pool admin and `init_args`.
+fn deploy_pool(
+    token_a: ContractHash,
+    token_b: ContractHash,
+    entry_points: EntryPoints,
+    init_entry_point: &str,
+    mut init_args: RuntimeArgs,
+) -> Key {
+    let count: u64 = storage::read(get_uref(POOL_COUNT))
+        .unwrap_or_revert()
+        .unwrap_or(0);
+    let package_key_name = format!("pool_package_{}", count);
+    let (pool_hash, _) = storage::new_contract(
+        entry_points,
+        None,
+        Some(package_key_name.clone()),
+        Some(format!("pool_access_{}", count)),
+        Some(BTreeMap::from([(
+            String::from(EVENTS_TOPIC),
+            MessageTopicOperation::Add,
+        )])),
+    );
+    let pool = Key::Hash(pool_hash.value());
+    register(token_a, token_b, pool);
+
+    let admin: Key = storage::read(get_uref(ADMIN))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let package_hash = runtime::get_key(&package_key_name).unwrap_or_revert();
+    init_args.insert("token_a", token_a).unwrap_or_revert();
+    init_args.insert("token_b", token_b).unwrap_or_revert();
+    init_args.insert("admin", admin).unwrap_or_revert();
+    init_args
+        .insert("package_hash", package_hash)
+        .unwrap_or_revert();
+    runtime::call_contract::<()>(pool_hash, init_entry_point, init_args);
+
+    pool
+}
+
+/// Deploys a new pool for the pair from the template entry points, initializes it with the
+/// factory admin as pool admin and fee recipient, and registers it. Passing `amp` makes it a
+/// StableSwap pool with that amplification coefficient. Passing `wcspr`, which must be one of the
+/// pair, enables the pool's CSPR swap entry points.
+#[no_mangle]
+pub extern "C" fn create_pool() {
+    let token_a = to_contract_hash(runtime::get_named_arg("token_a"));
+    let token_b = to_contract_hash(runtime::get_named_arg("token_b"));
+    let fee_bps: Option<u32> = runtime::try_get_named_arg("fee_bps");
+    let amp: Option<u64> = runtime::try_get_named_arg("amp");
+    let events_mode: Option<u8> = runtime::try_get_named_arg("events_mode");
+    let wcspr: Option<Key> = runtime::try_get_named_arg("wcspr");
+
+    let admin: Key = storage::read(get_uref(ADMIN))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let mut init_args = runtime_args! {
+        "fee_recipient" => admin,
+    };
+    if let Some(fee_bps) = fee_bps {
+        init_args.insert("fee_bps", fee_bps).unwrap_or_revert();
+    }
+    if let Some(amp) = amp {
+        init_args.insert("amp", amp).unwrap_or_revert();
+    }
+    if let Some(events_mode) = events_mode {
+        init_args
+            .insert("events_mode", events_mode)
+            .unwrap_or_revert();
+    }
+    if let Some(wcspr) = wcspr {
+        init_args
+            .insert("wcspr", to_contract_hash(wcspr))
+            .unwrap_or_revert();
+    }
+    let pool = deploy_pool(
+        token_a,
+        token_b,
+        pool_entry_points(EntryPointAccess::Public),
+        "init",
+        init_args,
+    );
+
+    runtime::ret(CLValue::from_t(pool).unwrap_or_revert());
+}
+
+/// Deploys a concentrated-liquidity pool for the pair, starting at the `sqrt_price_x96` price
+/// with positions aligned to `tick_spacing`, and registers it. A pair has a single pool, of
+/// either kind.
+#[no_mangle]
+pub extern "C" fn create_concentrated_pool() {
+    let token_a = to_contract_hash(runtime::get_named_arg("token_a"));
+    let token_b = to_contract_hash(runtime::get_named_arg("token_b"));
+    let tick_spacing: u32 = runtime::get_named_arg("tick_spacing");
+    let sqrt_price: U256 = runtime::get_named_arg("sqrt_price_x96");
+    let fee_bps: Option<u32> = runtime::try_get_named_arg("fee_bps");
+    let events_mode: Option<u8> = runtime::try_get_named_arg("events_mode");
+
+    let mut init_args = runtime_args! {
+        "tick_spacing" => tick_spacing,
+        "sqrt_price_x96" => sqrt_price,
+    };
+    if let Some(fee_bps) = fee_bps {
+        init_args.insert("fee_bps", fee_bps).unwrap_or_revert();
+    }
+    if let Some(events_mode) = events_mode {
+        init_args
+            .insert("events_mode", events_mode)
+            .unwrap_or_revert();
+    }
+    let pool = deploy_pool(
+        token_a,
+        token_b,
+        concentrated_pool_entry_points(EntryPointAccess::Public),
+        "init_concentrated",
+        init_args,
+    );
+
+    runtime::ret(CLValue::from_t(pool).unwrap_or_revert());
+}
+
+/// Admin EntryPoint registering a pool deployed outside the factory, such as the original
+/// single-pair AMM.
+#[no_mangle]
+pub extern "C" fn register_pool() {
+    ensure_admin();
+    let token_a = to_contract_hash(runtime::get_named_arg("token_a"));
+    let token_b = to_contract_hash(runtime::get_named_arg("token_b"));
+    let pool: Key = runtime::get_named_arg("pool");
+    register(token_a, token_b, pool);
+}
+
+#[no_mangle]
+pub extern "C" fn get_pool() {
+    let token_x = to_contract_hash(runtime::get_named_arg("token_x"));
+    let token_y = to_contract_hash(runtime::get_named_arg("token_y"));
+
+    let pool: Option<Key> =
+        storage::dictionary_get(get_uref(POOLS), &pair_key(token_x, token_y)).unwrap_or_revert();
+
+    runtime::ret(CLValue::from_t(pool).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn all_pools() {
+    let count: u64 = storage::read(get_uref(POOL_COUNT))
+        .unwrap_or_revert()
+        .unwrap_or(0);
+    let all_pools = get_uref(ALL_POOLS);
+
+    let mut pools: Vec<Key> = Vec::new();
+    for index in 0..count {
+        let pool: Key = storage::dictionary_get(all_pools, &index.to_string())
+            .unwrap_or_revert()
+            .unwrap_or_revert();
+        pools.push(pool);
+    }
+
+    runtime::ret(CLValue::from_t(pools).unwrap_or_revert());
+}
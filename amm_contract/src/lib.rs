@@ -3,20 +3,89 @@
 
 extern crate alloc;
 
-use alloc::{string::String, vec};
+mod concentrated;
+mod entry_points;
+mod events;
+mod factory;
+mod math;
+mod oracle;
+mod stableswap;
+
+use alloc::string::String;
 use casper_contract::contract_api::{runtime, storage};
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_types::{
-    CLType, CLTyped, CLValue, EntityEntryPoint as EntryPoint, 
-    EntryPointAccess, EntryPointPayment, EntryPointType, EntryPoints,
-    Key, Parameter, RuntimeArgs, URef, U256, runtime_args,
-    contracts::ContractHash,
+    bytesrepr::{Bytes, ToBytes}, CLValue, EntityAddr, Key, NamedKeys, RuntimeArgs, URef, U256, runtime_args,
+    account::AccountHash, contracts::{ContractHash, ContractPackageHash}, ApiError,
 };
+use events::{EventsMode, PoolEvent};
+use math::BPS_DENOMINATOR;
 
 const TOKEN_A: &str = "token_a";
 const TOKEN_B: &str = "token_b";
 const RESERVE_A: &str = "reserve_a";
 const RESERVE_B: &str = "reserve_b";
+const ADMIN: &str = "admin";
+const PACKAGE_HASH: &str = "package_hash";
+const FEE_BPS: &str = "fee_bps";
+const FEE_RECIPIENT: &str = "fee_recipient";
+const PROTOCOL_FEE_SHARE: &str = "protocol_fee_share";
+const PROTOCOL_FEES_A: &str = "protocol_fees_a";
+const PROTOCOL_FEES_B: &str = "protocol_fees_b";
+const LOCKED: &str = "locked";
+const PAUSED: &str = "paused";
+const AMP: &str = "amp";
+const WCSPR: &str = "wcspr";
+const TOTAL_SHARES: &str = "total_shares";
+const SHARES: &str = "shares";
+
+/// Message topic the pool emits its events on.
+pub(crate) const EVENTS_TOPIC: &str = "events";
+
+const DEFAULT_FEE_BPS: u32 = 30;
+/// Upper bound on the StableSwap amplification coefficient.
+const MAX_AMP: u64 = 1_000_000;
+/// Shares minted on the first deposit that no one owns, so the pool can never be fully drained
+/// back to dust.
+const MIN_LIQUIDITY: u64 = 1_000;
+
+const ARG_DEADLINE: &str = "deadline";
+const ARG_FEE_BPS: &str = "fee_bps";
+const ARG_FEE_RECIPIENT: &str = "fee_recipient";
+const ARG_PROTOCOL_FEE_SHARE: &str = "protocol_fee_share";
+const ARG_ADMIN: &str = "admin";
+const ARG_PACKAGE_HASH: &str = "package_hash";
+const ARG_RECIPIENT: &str = "recipient";
+const ARG_AMP: &str = "amp";
+const ARG_EVENTS_MODE: &str = "events_mode";
+const ARG_SHARES: &str = "shares";
+const ARG_USER: &str = "user";
+const ARG_WCSPR: &str = "wcspr";
+const ARG_PURSE: &str = "purse";
+
+// Error codes
+const ERROR_INSUFFICIENT_OUTPUT: u16 = 1;
+const ERROR_EXPIRED: u16 = 2;
+const ERROR_EXCESSIVE_INPUT: u16 = 3;
+const ERROR_INSUFFICIENT_LIQUIDITY: u16 = 4;
+const ERROR_INVALID_FEE: u16 = 5;
+const ERROR_NOT_ADMIN: u16 = 6;
+const ERROR_ALREADY_INITIALIZED: u16 = 7;
+const ERROR_POOL_EXISTS: u16 = 8;
+const ERROR_IDENTICAL_TOKENS: u16 = 9;
+const ERROR_INVALID_TOKEN: u16 = 10;
+const ERROR_INVALID_CONTEXT: u16 = 11;
+const ERROR_ORACLE_WINDOW: u16 = 12;
+const ERROR_LOCKED: u16 = 13;
+const ERROR_INSUFFICIENT_INPUT: u16 = 14;
+const ERROR_INVARIANT: u16 = 15;
+const ERROR_PAUSED: u16 = 16;
+const ERROR_INVALID_TICK: u16 = 17;
+const ERROR_MATH_OVERFLOW: u16 = 18;
+const ERROR_INVALID_PRICE: u16 = 19;
+const ERROR_INVALID_AMP: u16 = 20;
+const ERROR_INVALID_EVENTS_MODE: u16 = 21;
+const ERROR_WCSPR_NOT_SET: u16 = 22;
 
 fn get_uref(name: &str) -> URef {
     runtime::get_key(name)
@@ -25,19 +94,211 @@ fn get_uref(name: &str) -> URef {
         .unwrap_or_revert()
 }
 
+/// Key of the immediate caller, as seen by the CEP-18 tokens: the account for direct calls, the
+/// contract package for calls from another contract such as the router.
+fn get_immediate_caller() -> Key {
+    const ACCOUNT: u8 = 0;
+    const CONTRACT_PACKAGE: u8 = 2;
+    const ENTITY: u8 = 3;
+    const CONTRACT: u8 = 4;
+
+    let caller_info = runtime::get_immediate_caller().unwrap_or_revert();
+
+    match caller_info.kind() {
+        ACCOUNT => caller_info
+            .get_field_by_index(ACCOUNT)
+            .unwrap_or_revert()
+            .to_t::<Option<AccountHash>>()
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+            .into(),
+        CONTRACT => caller_info
+            .get_field_by_index(CONTRACT_PACKAGE)
+            .unwrap_or_revert()
+            .to_t::<Option<ContractPackageHash>>()
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+            .into(),
+        ENTITY => caller_info
+            .get_field_by_index(ENTITY)
+            .unwrap_or_revert()
+            .to_t::<Option<EntityAddr>>()
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+            .into(),
+        _ => runtime::revert(ApiError::User(ERROR_INVALID_CONTEXT)),
+    }
+}
+
+fn read_fee_bps() -> u32 {
+    storage::read(get_uref(FEE_BPS)).unwrap_or_revert().unwrap_or_revert()
+}
+
+fn ensure_admin() {
+    let admin: Key = storage::read(get_uref(ADMIN)).unwrap_or_revert().unwrap_or_revert();
+    if Key::from(runtime::get_caller()) != admin {
+        runtime::revert(ApiError::User(ERROR_NOT_ADMIN));
+    }
+}
+
+/// Acquires the pool mutex, reverting if a state-changing entry point is already running further
+/// up the call stack, e.g. when a token contract calls back into the pool mid-swap. Every
+/// state-changing entry point takes it first and releases it with [`unlock`] before returning.
+fn lock() {
+    let locked_uref = get_uref(LOCKED);
+    let locked: bool = storage::read(locked_uref).unwrap_or_revert().unwrap_or_revert();
+    if locked {
+        runtime::revert(ApiError::User(ERROR_LOCKED));
+    }
+    storage::write(locked_uref, true);
+}
+
+fn unlock() {
+    storage::write(get_uref(LOCKED), false);
+}
+
+/// Reverts while the admin has the pool paused. Only entry points that let value into the pool
+/// check it, so users can always get their funds out.
+fn ensure_not_paused() {
+    let paused: bool = storage::read(get_uref(PAUSED)).unwrap_or_revert().unwrap_or_revert();
+    if paused {
+        runtime::revert(ApiError::User(ERROR_PAUSED));
+    }
+}
+
+/// Reverts if the optional `deadline` argument (block time, in milliseconds) has passed.
+fn ensure_not_expired() {
+    if let Some(deadline) = runtime::try_get_named_arg::<u64>(ARG_DEADLINE) {
+        if u64::from(runtime::get_blocktime()) > deadline {
+            runtime::revert(ApiError::User(ERROR_EXPIRED));
+        }
+    }
+}
+
+/// Stores the optional `events_mode` argument (native messages by default) and registers the
+/// CES schemas if needed.
+fn init_events_mode() {
+    let events_mode: u8 =
+        runtime::try_get_named_arg(ARG_EVENTS_MODE).unwrap_or(EventsMode::Native as u8);
+    EventsMode::try_from(events_mode).unwrap_or_revert();
+    runtime::put_key(events::EVENTS_MODE, storage::new_uref(events_mode).into());
+    events::init_events();
+}
+
+/// Stores new reserves and emits a `Sync` event with them.
+fn write_reserves(reserve_a: U256, reserve_b: U256) {
+    storage::write(get_uref(RESERVE_A), reserve_a);
+    storage::write(get_uref(RESERVE_B), reserve_b);
+    events::emit_event(PoolEvent::Sync(events::Sync { reserve_a, reserve_b }));
+}
+
 #[no_mangle]
 pub extern "C" fn init() {
+    if runtime::has_key(TOKEN_A) {
+        runtime::revert(ApiError::User(ERROR_ALREADY_INITIALIZED));
+    }
     let token_a: ContractHash = runtime::get_named_arg("token_a");
     let token_b: ContractHash = runtime::get_named_arg("token_b");
+    let fee_bps: u32 = runtime::try_get_named_arg(ARG_FEE_BPS).unwrap_or(DEFAULT_FEE_BPS);
+    if fee_bps >= BPS_DENOMINATOR {
+        runtime::revert(ApiError::User(ERROR_INVALID_FEE));
+    }
+
+    let admin: Key =
+        runtime::try_get_named_arg(ARG_ADMIN).unwrap_or_else(|| Key::from(runtime::get_caller()));
+    let fee_recipient: Key = runtime::try_get_named_arg(ARG_FEE_RECIPIENT).unwrap_or(admin);
+    let amp: Option<u64> = runtime::try_get_named_arg(ARG_AMP);
+    if let Some(amp) = amp {
+        if amp == 0 || amp > MAX_AMP {
+            runtime::revert(ApiError::User(ERROR_INVALID_AMP));
+        }
+    }
     
     runtime::put_key(TOKEN_A, storage::new_uref(token_a).into());
     runtime::put_key(TOKEN_B, storage::new_uref(token_b).into());
     runtime::put_key(RESERVE_A, storage::new_uref(U256::zero()).into());
     runtime::put_key(RESERVE_B, storage::new_uref(U256::zero()).into());
+    runtime::put_key(ADMIN, storage::new_uref(admin).into());
+    runtime::put_key(FEE_BPS, storage::new_uref(fee_bps).into());
+    runtime::put_key(FEE_RECIPIENT, storage::new_uref(fee_recipient).into());
+    runtime::put_key(PROTOCOL_FEE_SHARE, storage::new_uref(0u32).into());
+    runtime::put_key(PROTOCOL_FEES_A, storage::new_uref(U256::zero()).into());
+    runtime::put_key(PROTOCOL_FEES_B, storage::new_uref(U256::zero()).into());
+    runtime::put_key(LOCKED, storage::new_uref(false).into());
+    runtime::put_key(PAUSED, storage::new_uref(false).into());
+    runtime::put_key(TOTAL_SHARES, storage::new_uref(U256::zero()).into());
+    storage::new_dictionary(SHARES).unwrap_or_revert();
+    if let Some(amp) = amp {
+        runtime::put_key(AMP, storage::new_uref(amp).into());
+    }
+    if let Some(wcspr) = runtime::try_get_named_arg::<ContractHash>(ARG_WCSPR) {
+        if wcspr != token_a && wcspr != token_b {
+            runtime::revert(ApiError::User(ERROR_INVALID_TOKEN));
+        }
+        runtime::put_key(WCSPR, storage::new_uref(wcspr).into());
+    }
+    oracle::init();
+    init_events_mode();
+
+    if let Some(package_hash) = runtime::try_get_named_arg::<Key>(ARG_PACKAGE_HASH) {
+        runtime::put_key(PACKAGE_HASH, package_hash);
+    }
+}
+
+/// Dictionary item key for `provider`'s LP shares.
+fn shares_key(provider: Key) -> String {
+    factory::hex_encode(&runtime::blake2b(provider.to_bytes().unwrap_or_revert()))
 }
 
+fn read_shares(provider: Key) -> U256 {
+    storage::dictionary_get(get_uref(SHARES), &shares_key(provider))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_shares(provider: Key, shares: U256) {
+    storage::dictionary_put(get_uref(SHARES), &shares_key(provider), shares);
+}
+
+/// LP shares a deposit of `amount_a`/`amount_b` is worth: `sqrt(amount_a * amount_b)` for the
+/// first deposit, of which [`MIN_LIQUIDITY`] stays unowned, and otherwise the smaller of the two
+/// proportional shares, so depositing off the reserve ratio only donates the excess. Returns
+/// `(shares_to_provider, shares_to_total_supply)`.
+fn shares_for_deposit(
+    amount_a: U256,
+    amount_b: U256,
+    reserve_a: U256,
+    reserve_b: U256,
+    total_shares: U256,
+) -> (U256, U256) {
+    if total_shares.is_zero() {
+        let root = math::narrow((math::widen(amount_a) * math::widen(amount_b)).integer_sqrt())
+            .unwrap_or_revert_with(ApiError::User(ERROR_MATH_OVERFLOW));
+        if root <= U256::from(MIN_LIQUIDITY) {
+            runtime::revert(ApiError::User(ERROR_INSUFFICIENT_LIQUIDITY));
+        }
+        return (root - U256::from(MIN_LIQUIDITY), root);
+    }
+
+    let shares_a = math::mul_div(amount_a, total_shares, reserve_a)
+        .unwrap_or_revert_with(ApiError::User(ERROR_MATH_OVERFLOW));
+    let shares_b = math::mul_div(amount_b, total_shares, reserve_b)
+        .unwrap_or_revert_with(ApiError::User(ERROR_MATH_OVERFLOW));
+    let shares = shares_a.min(shares_b);
+    if shares.is_zero() {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_LIQUIDITY));
+    }
+    (shares, shares)
+}
+
+/// Deposits `amount_a`/`amount_b` from the immediate caller and returns the LP shares credited to
+/// them. A contract calling in provides liquidity under its own package key, as tokens see it.
 #[no_mangle]
 pub extern "C" fn add_liquidity() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
     let amount_a: U256 = runtime::get_named_arg("amount_a");
     let amount_b: U256 = runtime::get_named_arg("amount_b");
     
@@ -46,14 +307,35 @@ pub extern "C" fn add_liquidity() {
     let token_a: ContractHash = storage::read(token_a_uref).unwrap_or_revert().unwrap_or_revert();
     let token_b: ContractHash = storage::read(token_b_uref).unwrap_or_revert().unwrap_or_revert();
     
-    let caller = runtime::get_caller();
+    let reserve_a_uref = get_uref(RESERVE_A);
+    let reserve_b_uref = get_uref(RESERVE_B);
+    
+    let current_a: U256 = storage::read(reserve_a_uref).unwrap_or_revert().unwrap_or(U256::zero());
+    let current_b: U256 = storage::read(reserve_b_uref).unwrap_or_revert().unwrap_or(U256::zero());
+    
+    let caller = get_immediate_caller();
+    let total_shares_uref = get_uref(TOTAL_SHARES);
+    let total_shares: U256 = storage::read(total_shares_uref).unwrap_or_revert().unwrap_or_revert();
+    let (shares, minted) = shares_for_deposit(amount_a, amount_b, current_a, current_b, total_shares);
+    storage::write(total_shares_uref, total_shares + minted);
+    write_shares(caller, read_shares(caller) + shares);
+    
+    oracle::update(current_a, current_b);
+    write_reserves(current_a + amount_a, current_b + amount_b);
     
+    events::emit_event(PoolEvent::LiquidityAdded(events::LiquidityAdded {
+        provider: caller,
+        amount_a,
+        amount_b,
+    }));
+    
+    let pool = self_key();
     runtime::call_contract::<()>(
         token_a,
         "transfer_from",
         runtime_args! {
-            "owner" => Key::from(caller),
-            "recipient" => Key::from(runtime::get_caller()),
+            "owner" => caller,
+            "recipient" => pool,
             "amount" => amount_a,
         },
     );
@@ -62,128 +344,743 @@ pub extern "C" fn add_liquidity() {
         token_b,
         "transfer_from",
         runtime_args! {
-            "owner" => Key::from(caller),
-            "recipient" => Key::from(runtime::get_caller()),
+            "owner" => caller,
+            "recipient" => pool,
             "amount" => amount_b,
         },
     );
     
-    let reserve_a_uref = get_uref(RESERVE_A);
-    let reserve_b_uref = get_uref(RESERVE_B);
-    
-    let current_a: U256 = storage::read(reserve_a_uref).unwrap_or_revert().unwrap_or(U256::zero());
-    let current_b: U256 = storage::read(reserve_b_uref).unwrap_or_revert().unwrap_or(U256::zero());
-    
-    storage::write(reserve_a_uref, current_a + amount_a);
-    storage::write(reserve_b_uref, current_b + amount_b);
-    
-    runtime::ret(CLValue::from_t(true).unwrap_or_revert());
+    unlock();
+    runtime::ret(CLValue::from_t(shares).unwrap_or_revert());
 }
 
+/// Burns `shares` of the caller's LP shares and pays out their pro-rata part of both reserves.
+/// Like every exit, it is not blocked by a pause. Returns `(amount_a, amount_b)`.
 #[no_mangle]
-pub extern "C" fn swap_a_to_b() {
-    let amount_in: U256 = runtime::get_named_arg("amount_in");
-    let min_out: U256 = runtime::get_named_arg("min_amount_out");
-    
-    let reserve_a_uref = get_uref(RESERVE_A);
-    let reserve_b_uref = get_uref(RESERVE_B);
-    
-    let reserve_a: U256 = storage::read(reserve_a_uref).unwrap_or_revert().unwrap_or_revert();
-    let reserve_b: U256 = storage::read(reserve_b_uref).unwrap_or_revert().unwrap_or_revert();
-    
-    let amount_in_with_fee = amount_in * U256::from(997);
-    let numerator = amount_in_with_fee * reserve_b;
-    let denominator = (reserve_a * U256::from(1000)) + amount_in_with_fee;
-    let amount_out = numerator / denominator;
-    
-    if amount_out < min_out {
-        runtime::revert(casper_types::ApiError::User(1));
+pub extern "C" fn remove_liquidity() {
+    lock();
+    ensure_not_expired();
+
+    let shares: U256 = runtime::get_named_arg(ARG_SHARES);
+    let provider = get_immediate_caller();
+    let provider_shares = read_shares(provider);
+    if shares.is_zero() || shares > provider_shares {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_LIQUIDITY));
     }
-    
-    let token_a_uref = get_uref(TOKEN_A);
-    let token_b_uref = get_uref(TOKEN_B);
-    let token_a: ContractHash = storage::read(token_a_uref).unwrap_or_revert().unwrap_or_revert();
-    let token_b: ContractHash = storage::read(token_b_uref).unwrap_or_revert().unwrap_or_revert();
-    
-    let caller = runtime::get_caller();
-    
+
+    let total_shares_uref = get_uref(TOTAL_SHARES);
+    let total_shares: U256 = storage::read(total_shares_uref).unwrap_or_revert().unwrap_or_revert();
+    let (reserve_a, reserve_b) = read_reserves();
+    let amount_a = math::mul_div(shares, reserve_a, total_shares)
+        .unwrap_or_revert_with(ApiError::User(ERROR_MATH_OVERFLOW));
+    let amount_b = math::mul_div(shares, reserve_b, total_shares)
+        .unwrap_or_revert_with(ApiError::User(ERROR_MATH_OVERFLOW));
+
+    write_shares(provider, provider_shares - shares);
+    storage::write(total_shares_uref, total_shares - shares);
+    oracle::update(reserve_a, reserve_b);
+    write_reserves(reserve_a - amount_a, reserve_b - amount_b);
+    events::emit_event(PoolEvent::LiquidityRemoved(events::LiquidityRemoved {
+        provider,
+        amount_a,
+        amount_b,
+    }));
+
+    let token_a: ContractHash = storage::read(get_uref(TOKEN_A)).unwrap_or_revert().unwrap_or_revert();
+    let token_b: ContractHash = storage::read(get_uref(TOKEN_B)).unwrap_or_revert().unwrap_or_revert();
+    for (token, amount) in [(token_a, amount_a), (token_b, amount_b)] {
+        if !amount.is_zero() {
+            runtime::call_contract::<()>(
+                token,
+                "transfer",
+                runtime_args! {
+                    "recipient" => provider,
+                    "amount" => amount,
+                },
+            );
+        }
+    }
+
+    unlock();
+    runtime::ret(CLValue::from_t((amount_a, amount_b)).unwrap_or_revert());
+}
+
+/// Amplification coefficient of a StableSwap pool, `None` for a constant-product pool.
+fn read_amp() -> Option<u64> {
+    if !runtime::has_key(AMP) {
+        return None;
+    }
+    Some(storage::read(get_uref(AMP)).unwrap_or_revert().unwrap_or_revert())
+}
+
+/// Output amount for an exact `amount_in` on the pool's curve.
+fn amount_out_for(amount_in: U256, reserve_in: U256, reserve_out: U256, fee_bps: u32) -> U256 {
+    match read_amp() {
+        Some(amp) => stableswap::get_amount_out(amount_in, reserve_in, reserve_out, fee_bps, amp)
+            .unwrap_or_revert_with(ApiError::User(ERROR_INSUFFICIENT_LIQUIDITY)),
+        None => math::get_amount_out(amount_in, reserve_in, reserve_out, fee_bps),
+    }
+}
+
+/// Input amount for an exact `amount_out` on the pool's curve, reverting if the pool cannot pay
+/// it out.
+fn required_amount_in(amount_out: U256, reserve_in: U256, reserve_out: U256, fee_bps: u32) -> U256 {
+    match read_amp() {
+        Some(amp) => stableswap::get_amount_in(amount_out, reserve_in, reserve_out, fee_bps, amp),
+        None => math::get_amount_in(amount_out, reserve_in, reserve_out, fee_bps),
+    }
+    .unwrap_or_revert_with(ApiError::User(ERROR_INSUFFICIENT_LIQUIDITY))
+}
+
+/// Marginal price of the base token in the quote token on the pool's curve, scaled by 10^18.
+fn spot_price(reserve_base: U256, reserve_quote: U256) -> U256 {
+    match read_amp() {
+        Some(amp) => stableswap::spot_price(reserve_base, reserve_quote, amp),
+        None => math::spot_price(reserve_base, reserve_quote),
+    }
+}
+
+fn read_reserves() -> (U256, U256) {
+    let reserve_a: U256 = storage::read(get_uref(RESERVE_A)).unwrap_or_revert().unwrap_or_revert();
+    let reserve_b: U256 = storage::read(get_uref(RESERVE_B)).unwrap_or_revert().unwrap_or_revert();
+    (reserve_a, reserve_b)
+}
+
+/// Sets aside the protocol's share of the swap fee on `amount_in` under `protocol_fees_key` and
+/// returns it.
+fn accrue_protocol_fee(protocol_fees_key: &str, amount_in: U256) -> U256 {
+    let protocol_fee_share: u32 = storage::read(get_uref(PROTOCOL_FEE_SHARE))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let protocol_fee = amount_in * U256::from(read_fee_bps()) * U256::from(protocol_fee_share)
+        / U256::from(BPS_DENOMINATOR)
+        / U256::from(BPS_DENOMINATOR);
+    let protocol_fees_uref = get_uref(protocol_fees_key);
+    let protocol_fees: U256 = storage::read(protocol_fees_uref).unwrap_or_revert().unwrap_or_revert();
+    storage::write(protocol_fees_uref, protocol_fees + protocol_fee);
+    protocol_fee
+}
+
+/// Books both legs of a swap against the reserves and emits the `Swap` event, returning the
+/// input and output tokens. The protocol's share of the swap fee is set aside for the fee
+/// recipient instead of being added to the input reserve. Callers move the tokens afterwards, so
+/// a token calling back in never sees stale reserves.
+fn book_swap(
+    a_to_b: bool,
+    amount_in: U256,
+    amount_out: U256,
+    trader: Key,
+    recipient: Key,
+) -> (ContractHash, ContractHash) {
+    let (reserve_a, reserve_b) = read_reserves();
+
+    let protocol_fee = accrue_protocol_fee(if a_to_b { PROTOCOL_FEES_A } else { PROTOCOL_FEES_B }, amount_in);
+    let amount_in_to_reserve = amount_in - protocol_fee;
+
+    oracle::update(reserve_a, reserve_b);
+    if a_to_b {
+        write_reserves(reserve_a + amount_in_to_reserve, reserve_b - amount_out);
+    } else {
+        write_reserves(reserve_a - amount_out, reserve_b + amount_in_to_reserve);
+    }
+
+    let (amount_a_in, amount_b_in, amount_a_out, amount_b_out) = if a_to_b {
+        (amount_in, U256::zero(), U256::zero(), amount_out)
+    } else {
+        (U256::zero(), amount_in, amount_out, U256::zero())
+    };
+    events::emit_event(PoolEvent::Swap(events::Swap {
+        sender: trader,
+        amount_a_in,
+        amount_b_in,
+        amount_a_out,
+        amount_b_out,
+        recipient,
+    }));
+
+    let token_a: ContractHash = storage::read(get_uref(TOKEN_A)).unwrap_or_revert().unwrap_or_revert();
+    let token_b: ContractHash = storage::read(get_uref(TOKEN_B)).unwrap_or_revert().unwrap_or_revert();
+    if a_to_b { (token_a, token_b) } else { (token_b, token_a) }
+}
+
+/// Pulls `amount_in` of the input token from the immediate caller into the pool.
+fn pull_input(token_in: ContractHash, trader: Key, amount_in: U256) {
     runtime::call_contract::<()>(
-        token_a,
+        token_in,
         "transfer_from",
         runtime_args! {
-            "owner" => Key::from(caller),
-            "recipient" => Key::from(runtime::get_caller()),
+            "owner" => trader,
+            "recipient" => self_key(),
             "amount" => amount_in,
         },
     );
-    
+}
+
+/// Books a swap with [`book_swap`], then pulls `amount_in` of the input token from the immediate
+/// caller into the pool and pays out `amount_out` of the output token to the optional `recipient`
+/// (the caller by default).
+fn settle_swap(a_to_b: bool, amount_in: U256, amount_out: U256) {
+    let trader = get_immediate_caller();
+    let recipient: Key = runtime::try_get_named_arg(ARG_RECIPIENT).unwrap_or(trader);
+    let (token_in, token_out) = book_swap(a_to_b, amount_in, amount_out, trader, recipient);
+
+    pull_input(token_in, trader, amount_in);
+
     runtime::call_contract::<()>(
-        token_b,
+        token_out,
         "transfer",
         runtime_args! {
-            "recipient" => Key::from(caller),
+            "recipient" => recipient,
             "amount" => amount_out,
         },
     );
-    
-    storage::write(reserve_a_uref, reserve_a + amount_in);
-    storage::write(reserve_b_uref, reserve_b - amount_out);
-    
+}
+
+#[no_mangle]
+pub extern "C" fn swap_a_to_b() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
+    let amount_in: U256 = runtime::get_named_arg("amount_in");
+    let min_out: U256 = runtime::get_named_arg("min_amount_out");
+
+    let amount_out = if concentrated::is_concentrated() {
+        concentrated::swap_exact_input(true, amount_in, min_out)
+    } else {
+        let (reserve_a, reserve_b) = read_reserves();
+        let amount_out = amount_out_for(amount_in, reserve_a, reserve_b, read_fee_bps());
+
+        if amount_out < min_out {
+            runtime::revert(ApiError::User(ERROR_INSUFFICIENT_OUTPUT));
+        }
+
+        settle_swap(true, amount_in, amount_out);
+        amount_out
+    };
+
+    unlock();
     runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
 }
 
 #[no_mangle]
 pub extern "C" fn swap_b_to_a() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
     let amount_in: U256 = runtime::get_named_arg("amount_in");
     let min_out: U256 = runtime::get_named_arg("min_amount_out");
-    
-    let reserve_a_uref = get_uref(RESERVE_A);
-    let reserve_b_uref = get_uref(RESERVE_B);
-    
-    let reserve_a: U256 = storage::read(reserve_a_uref).unwrap_or_revert().unwrap_or_revert();
-    let reserve_b: U256 = storage::read(reserve_b_uref).unwrap_or_revert().unwrap_or_revert();
-    
-    let amount_in_with_fee = amount_in * U256::from(997);
-    let numerator = amount_in_with_fee * reserve_a;
-    let denominator = (reserve_b * U256::from(1000)) + amount_in_with_fee;
-    let amount_out = numerator / denominator;
-    
+
+    let amount_out = if concentrated::is_concentrated() {
+        concentrated::swap_exact_input(false, amount_in, min_out)
+    } else {
+        let (reserve_a, reserve_b) = read_reserves();
+        let amount_out = amount_out_for(amount_in, reserve_b, reserve_a, read_fee_bps());
+
+        if amount_out < min_out {
+            runtime::revert(ApiError::User(ERROR_INSUFFICIENT_OUTPUT));
+        }
+
+        settle_swap(false, amount_in, amount_out);
+        amount_out
+    };
+
+    unlock();
+    runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn swap_a_for_exact_b() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
+    let amount_out: U256 = runtime::get_named_arg("amount_out");
+    let max_in: U256 = runtime::get_named_arg("max_amount_in");
+
+    let (reserve_a, reserve_b) = read_reserves();
+    let amount_in = required_amount_in(amount_out, reserve_a, reserve_b, read_fee_bps());
+
+    if amount_in > max_in {
+        runtime::revert(ApiError::User(ERROR_EXCESSIVE_INPUT));
+    }
+
+    settle_swap(true, amount_in, amount_out);
+
+    unlock();
+    runtime::ret(CLValue::from_t(amount_in).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn swap_b_for_exact_a() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
+    let amount_out: U256 = runtime::get_named_arg("amount_out");
+    let max_in: U256 = runtime::get_named_arg("max_amount_in");
+
+    let (reserve_a, reserve_b) = read_reserves();
+    let amount_in = required_amount_in(amount_out, reserve_b, reserve_a, read_fee_bps());
+
+    if amount_in > max_in {
+        runtime::revert(ApiError::User(ERROR_EXCESSIVE_INPUT));
+    }
+
+    settle_swap(false, amount_in, amount_out);
+
+    unlock();
+    runtime::ret(CLValue::from_t(amount_in).unwrap_or_revert());
+}
+
+/// Returns the pool's WCSPR token and whether it is token A, reverting on pools created without
+/// `wcspr`.
+fn read_wcspr() -> (ContractHash, bool) {
+    if !runtime::has_key(WCSPR) {
+        runtime::revert(ApiError::User(ERROR_WCSPR_NOT_SET));
+    }
+    let wcspr: ContractHash = storage::read(get_uref(WCSPR)).unwrap_or_revert().unwrap_or_revert();
+    let token_a: ContractHash = storage::read(get_uref(TOKEN_A)).unwrap_or_revert().unwrap_or_revert();
+    (wcspr, wcspr == token_a)
+}
+
+/// Output of an exact-input swap on the pool's curve, reverting below `min_out`.
+fn checked_amount_out(a_to_b: bool, amount_in: U256, min_out: U256) -> U256 {
+    let (reserve_a, reserve_b) = read_reserves();
+    let (reserve_in, reserve_out) = if a_to_b { (reserve_a, reserve_b) } else { (reserve_b, reserve_a) };
+    let amount_out = amount_out_for(amount_in, reserve_in, reserve_out, read_fee_bps());
     if amount_out < min_out {
-        runtime::revert(casper_types::ApiError::User(1));
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_OUTPUT));
     }
-    
-    let token_a_uref = get_uref(TOKEN_A);
-    let token_b_uref = get_uref(TOKEN_B);
-    let token_a: ContractHash = storage::read(token_a_uref).unwrap_or_revert().unwrap_or_revert();
-    let token_b: ContractHash = storage::read(token_b_uref).unwrap_or_revert().unwrap_or_revert();
-    
-    let caller = runtime::get_caller();
-    
+    amount_out
+}
+
+/// Wraps `amount_in` motes from `purse` into WCSPR held by the pool and swaps them for the other
+/// token, paid to the optional `recipient` (the caller by default). The caller's session passes a
+/// purse it funded with the CSPR to swap.
+#[no_mangle]
+pub extern "C" fn swap_cspr_for_tokens() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
+    let amount_in: U256 = runtime::get_named_arg("amount_in");
+    let min_out: U256 = runtime::get_named_arg("min_amount_out");
+    let purse: URef = runtime::get_named_arg(ARG_PURSE);
+
+    let (wcspr, a_to_b) = read_wcspr();
+    let amount_out = checked_amount_out(a_to_b, amount_in, min_out);
+
+    let trader = get_immediate_caller();
+    let recipient: Key = runtime::try_get_named_arg(ARG_RECIPIENT).unwrap_or(trader);
+    let (_, token_out) = book_swap(a_to_b, amount_in, amount_out, trader, recipient);
+
+    // WCSPR mints to its immediate caller, so the deposit lands straight in the pool.
     runtime::call_contract::<()>(
-        token_b,
-        "transfer_from",
+        wcspr,
+        "deposit",
         runtime_args! {
-            "owner" => Key::from(caller),
-            "recipient" => Key::from(runtime::get_caller()),
+            "purse" => purse,
             "amount" => amount_in,
         },
     );
-    
     runtime::call_contract::<()>(
-        token_a,
+        token_out,
         "transfer",
         runtime_args! {
-            "recipient" => Key::from(caller),
+            "recipient" => recipient,
             "amount" => amount_out,
         },
     );
-    
-    storage::write(reserve_a_uref, reserve_a - amount_out);
-    storage::write(reserve_b_uref, reserve_b + amount_in);
-    
+
+    unlock();
+    runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
+}
+
+/// Swaps an exact `amount_in` of the other token for WCSPR and unwraps the output into `purse` as
+/// CSPR.
+#[no_mangle]
+pub extern "C" fn swap_tokens_for_cspr() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
+    let amount_in: U256 = runtime::get_named_arg("amount_in");
+    let min_out: U256 = runtime::get_named_arg("min_amount_out");
+    let purse: URef = runtime::get_named_arg(ARG_PURSE);
+
+    let (wcspr, wcspr_is_a) = read_wcspr();
+    let a_to_b = !wcspr_is_a;
+    let amount_out = checked_amount_out(a_to_b, amount_in, min_out);
+
+    let trader = get_immediate_caller();
+    let (token_in, _) = book_swap(a_to_b, amount_in, amount_out, trader, trader);
+
+    pull_input(token_in, trader, amount_in);
+    runtime::call_contract::<()>(
+        wcspr,
+        "withdraw",
+        runtime_args! {
+            "purse" => purse,
+            "amount" => amount_out,
+        },
+    );
+
+    unlock();
+    runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
+}
+
+/// Sends `amount_a_out`/`amount_b_out` to `recipient`, calls
+/// `flash_swap_callback(sender, amount_a_out, amount_b_out, data)` on `callee`, then requires the
+/// pool's balances to satisfy the constant product with fees. The callee repays by transferring
+/// tokens to the pool during the callback, in either token, so no capital is needed up front.
+/// `recipient` has no default: `callee` is a contract hash, while tokens key contracts by their
+/// package, so the borrower's package key has to be passed explicitly.
+#[no_mangle]
+pub extern "C" fn flash_swap() {
+    lock();
+    ensure_not_paused();
+    ensure_not_expired();
+
+    let amount_a_out: U256 = runtime::get_named_arg("amount_a_out");
+    let amount_b_out: U256 = runtime::get_named_arg("amount_b_out");
+    let callee: Key = runtime::get_named_arg("callee");
+    let data: Bytes = runtime::get_named_arg("data");
+    let recipient: Key = runtime::get_named_arg(ARG_RECIPIENT);
+
+    if amount_a_out.is_zero() && amount_b_out.is_zero() {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_OUTPUT));
+    }
+    let (reserve_a, reserve_b) = read_reserves();
+    if amount_a_out >= reserve_a || amount_b_out >= reserve_b {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_LIQUIDITY));
+    }
+
+    let token_a: ContractHash = storage::read(get_uref(TOKEN_A)).unwrap_or_revert().unwrap_or_revert();
+    let token_b: ContractHash = storage::read(get_uref(TOKEN_B)).unwrap_or_revert().unwrap_or_revert();
+    for (token, amount) in [(token_a, amount_a_out), (token_b, amount_b_out)] {
+        if !amount.is_zero() {
+            runtime::call_contract::<()>(
+                token,
+                "transfer",
+                runtime_args! {
+                    "recipient" => recipient,
+                    "amount" => amount,
+                },
+            );
+        }
+    }
+
+    let callee_hash = callee
+        .into_hash_addr()
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(ApiError::User(ERROR_INVALID_CONTEXT));
+    runtime::call_contract::<()>(
+        callee_hash,
+        "flash_swap_callback",
+        runtime_args! {
+            "sender" => get_immediate_caller(),
+            "amount_a_out" => amount_a_out,
+            "amount_b_out" => amount_b_out,
+            "data" => data,
+        },
+    );
+
+    let (balance_a, balance_b) = observed_reserves();
+    let amount_a_in = balance_a.saturating_sub(reserve_a - amount_a_out);
+    let amount_b_in = balance_b.saturating_sub(reserve_b - amount_b_out);
+    if amount_a_in.is_zero() && amount_b_in.is_zero() {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_INPUT));
+    }
+    let fee_bps = read_fee_bps();
+    let invariant_holds = match read_amp() {
+        Some(amp) => stableswap::invariant_holds(
+            (balance_a, balance_b),
+            (amount_a_in, amount_b_in),
+            (reserve_a, reserve_b),
+            fee_bps,
+            amp,
+        ),
+        None => math::invariant_holds(balance_a, balance_b, amount_a_in, amount_b_in, reserve_a, reserve_b, fee_bps),
+    };
+    if !invariant_holds {
+        runtime::revert(ApiError::User(ERROR_INVARIANT));
+    }
+
+    let protocol_fee_a = accrue_protocol_fee(PROTOCOL_FEES_A, amount_a_in);
+    let protocol_fee_b = accrue_protocol_fee(PROTOCOL_FEES_B, amount_b_in);
+    oracle::update(reserve_a, reserve_b);
+    write_reserves(balance_a - protocol_fee_a, balance_b - protocol_fee_b);
+    events::emit_event(PoolEvent::Swap(events::Swap {
+        sender: get_immediate_caller(),
+        amount_a_in,
+        amount_b_in,
+        amount_a_out,
+        amount_b_out,
+        recipient,
+    }));
+    unlock();
+}
+
+/// Admin EntryPoint halting swaps, flash swaps and liquidity adds. Liquidity removal, protocol
+/// fee collection, `sync` and `skim` stay available.
+#[no_mangle]
+pub extern "C" fn pause() {
+    set_paused(true);
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    set_paused(false);
+}
+
+fn set_paused(paused: bool) {
+    lock();
+    ensure_admin();
+    storage::write(get_uref(PAUSED), paused);
+
+    let admin = Key::from(runtime::get_caller());
+    events::emit_event(if paused {
+        PoolEvent::Paused(events::Paused { admin })
+    } else {
+        PoolEvent::Unpaused(events::Unpaused { admin })
+    });
+    unlock();
+}
+
+/// Admin EntryPoint switching how the pool emits events (0 none, 1 CES, 2 native messages, 3
+/// native messages as bytes).
+#[no_mangle]
+pub extern "C" fn change_events_mode() {
+    lock();
+    ensure_admin();
+    let events_mode: u8 = runtime::get_named_arg(ARG_EVENTS_MODE);
+    EventsMode::try_from(events_mode).unwrap_or_revert();
+
+    if runtime::has_key(events::EVENTS_MODE) {
+        storage::write(get_uref(events::EVENTS_MODE), events_mode);
+    } else {
+        runtime::put_key(events::EVENTS_MODE, storage::new_uref(events_mode).into());
+    }
+    events::init_events();
+    events::emit_event(PoolEvent::ChangeEventsMode(events::ChangeEventsMode { events_mode }));
+    unlock();
+}
+
+/// Admin EntryPoint setting the share of the swap fee, in basis points of the fee, that accrues
+/// to the fee recipient instead of liquidity providers. Zero switches the protocol fee off.
+#[no_mangle]
+pub extern "C" fn set_protocol_fee() {
+    lock();
+    ensure_admin();
+    let share: u32 = runtime::get_named_arg(ARG_PROTOCOL_FEE_SHARE);
+    if share > BPS_DENOMINATOR {
+        runtime::revert(ApiError::User(ERROR_INVALID_FEE));
+    }
+    storage::write(get_uref(PROTOCOL_FEE_SHARE), share);
+    unlock();
+}
+
+#[no_mangle]
+pub extern "C" fn set_fee_recipient() {
+    lock();
+    ensure_admin();
+    let recipient: Key = runtime::get_named_arg(ARG_FEE_RECIPIENT);
+    storage::write(get_uref(FEE_RECIPIENT), recipient);
+    unlock();
+}
+
+/// Sends all accrued protocol fees to the fee recipient and returns the amounts collected.
+#[no_mangle]
+pub extern "C" fn collect_protocol_fees() {
+    lock();
+    ensure_not_expired();
+    let fee_recipient: Key = storage::read(get_uref(FEE_RECIPIENT)).unwrap_or_revert().unwrap_or_revert();
+    if Key::from(runtime::get_caller()) != fee_recipient {
+        ensure_admin();
+    }
+
+    let fees_a_uref = get_uref(PROTOCOL_FEES_A);
+    let fees_b_uref = get_uref(PROTOCOL_FEES_B);
+    let fees_a: U256 = storage::read(fees_a_uref).unwrap_or_revert().unwrap_or_revert();
+    let fees_b: U256 = storage::read(fees_b_uref).unwrap_or_revert().unwrap_or_revert();
+    storage::write(fees_a_uref, U256::zero());
+    storage::write(fees_b_uref, U256::zero());
+
+    let token_a: ContractHash = storage::read(get_uref(TOKEN_A)).unwrap_or_revert().unwrap_or_revert();
+    let token_b: ContractHash = storage::read(get_uref(TOKEN_B)).unwrap_or_revert().unwrap_or_revert();
+    for (token, amount) in [(token_a, fees_a), (token_b, fees_b)] {
+        if !amount.is_zero() {
+            runtime::call_contract::<()>(
+                token,
+                "transfer",
+                runtime_args! {
+                    "recipient" => fee_recipient,
+                    "amount" => amount,
+                },
+            );
+        }
+    }
+
+    unlock();
+    runtime::ret(CLValue::from_t((fees_a, fees_b)).unwrap_or_revert());
+}
+
+/// Key the tokens record the pool's balances under: the pool's contract package, as seen by a
+/// CEP-18 token when the pool calls it.
+fn self_key() -> Key {
+    match runtime::get_key(PACKAGE_HASH).unwrap_or_revert_with(ApiError::User(ERROR_INVALID_CONTEXT)) {
+        Key::Hash(package_hash) | Key::SmartContract(package_hash) => Key::Hash(package_hash),
+        _ => runtime::revert(ApiError::User(ERROR_INVALID_CONTEXT)),
+    }
+}
+
+fn balance_of(token: ContractHash, address: Key) -> U256 {
+    runtime::call_contract(token, "balance_of", runtime_args! { "address" => address })
+}
+
+/// Pool balances of both tokens, less the protocol fees they include: what the reserves should
+/// be if nothing had drifted.
+fn observed_reserves() -> (U256, U256) {
+    let token_a: ContractHash = storage::read(get_uref(TOKEN_A)).unwrap_or_revert().unwrap_or_revert();
+    let token_b: ContractHash = storage::read(get_uref(TOKEN_B)).unwrap_or_revert().unwrap_or_revert();
+    let fees_a: U256 = storage::read(get_uref(PROTOCOL_FEES_A)).unwrap_or_revert().unwrap_or_revert();
+    let fees_b: U256 = storage::read(get_uref(PROTOCOL_FEES_B)).unwrap_or_revert().unwrap_or_revert();
+
+    let pool = self_key();
+    (
+        balance_of(token_a, pool).saturating_sub(fees_a),
+        balance_of(token_b, pool).saturating_sub(fees_b),
+    )
+}
+
+/// Sets the reserves to the pool's actual token balances (less accrued protocol fees), e.g. after
+/// tokens were sent to the pool directly.
+#[no_mangle]
+pub extern "C" fn sync() {
+    lock();
+    ensure_not_expired();
+    let (reserve_a, reserve_b) = read_reserves();
+    let (balance_a, balance_b) = observed_reserves();
+
+    oracle::update(reserve_a, reserve_b);
+    write_reserves(balance_a, balance_b);
+    unlock();
+}
+
+/// Sends any token balance above the reserves (and accrued protocol fees) to `to`, and returns the
+/// amounts sent.
+#[no_mangle]
+pub extern "C" fn skim() {
+    lock();
+    ensure_not_expired();
+    let to: Key = runtime::get_named_arg("to");
+
+    let (reserve_a, reserve_b) = read_reserves();
+    let (balance_a, balance_b) = observed_reserves();
+    let excess_a = balance_a.saturating_sub(reserve_a);
+    let excess_b = balance_b.saturating_sub(reserve_b);
+
+    let token_a: ContractHash = storage::read(get_uref(TOKEN_A)).unwrap_or_revert().unwrap_or_revert();
+    let token_b: ContractHash = storage::read(get_uref(TOKEN_B)).unwrap_or_revert().unwrap_or_revert();
+    for (token, amount) in [(token_a, excess_a), (token_b, excess_b)] {
+        if !amount.is_zero() {
+            runtime::call_contract::<()>(
+                token,
+                "transfer",
+                runtime_args! {
+                    "recipient" => to,
+                    "amount" => amount,
+                },
+            );
+        }
+    }
+
+    unlock();
+    runtime::ret(CLValue::from_t((excess_a, excess_b)).unwrap_or_revert());
+}
+
+/// Read-only preview of `swap_a_to_b` (`a_to_b = true`) or `swap_b_to_a`.
+#[no_mangle]
+pub extern "C" fn get_amount_out() {
+    let amount_in: U256 = runtime::get_named_arg("amount_in");
+    let a_to_b: bool = runtime::get_named_arg("a_to_b");
+
+    let (reserve_a, reserve_b) = read_reserves();
+    let (reserve_in, reserve_out) = if a_to_b { (reserve_a, reserve_b) } else { (reserve_b, reserve_a) };
+    let amount_out = amount_out_for(amount_in, reserve_in, reserve_out, read_fee_bps());
+
     runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
 }
 
+/// Read-only preview of `swap_a_for_exact_b` (`a_to_b = true`) or `swap_b_for_exact_a`.
+#[no_mangle]
+pub extern "C" fn get_amount_in() {
+    let amount_out: U256 = runtime::get_named_arg("amount_out");
+    let a_to_b: bool = runtime::get_named_arg("a_to_b");
+
+    let (reserve_a, reserve_b) = read_reserves();
+    let (reserve_in, reserve_out) = if a_to_b { (reserve_a, reserve_b) } else { (reserve_b, reserve_a) };
+    let amount_in = required_amount_in(amount_out, reserve_in, reserve_out, read_fee_bps());
+
+    runtime::ret(CLValue::from_t(amount_in).unwrap_or_revert());
+}
+
+/// Returns `(price_a_in_b, price_b_in_a)`, each scaled by 10^18.
+#[no_mangle]
+pub extern "C" fn get_spot_price() {
+    let (reserve_a, reserve_b) = read_reserves();
+
+    let prices = (
+        spot_price(reserve_a, reserve_b),
+        spot_price(reserve_b, reserve_a),
+    );
+
+    runtime::ret(CLValue::from_t(prices).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_user_shares() {
+    let user: Key = runtime::get_named_arg(ARG_USER);
+    runtime::ret(CLValue::from_t(read_shares(user)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_total_shares() {
+    let total_shares: U256 = storage::read(get_uref(TOTAL_SHARES)).unwrap_or_revert().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(total_shares).unwrap_or_revert());
+}
+
+/// Returns the `(amount_a, amount_b)` deposit, bounded by the desired amounts, that matches the
+/// current reserve ratio.
+#[no_mangle]
+pub extern "C" fn quote_add_liquidity() {
+    let amount_a: U256 = runtime::get_named_arg("amount_a");
+    let amount_b: U256 = runtime::get_named_arg("amount_b");
+
+    let (reserve_a, reserve_b) = read_reserves();
+    let amounts = math::optimal_liquidity(amount_a, amount_b, reserve_a, reserve_b);
+
+    runtime::ret(CLValue::from_t(amounts).unwrap_or_revert());
+}
+
+/// Returns `(token_a, token_b, package_hash)`, letting the router pick the swap direction and
+/// approve the pool as spender.
+#[no_mangle]
+pub extern "C" fn get_pool_info() {
+    let token_a: ContractHash = storage::read(get_uref(TOKEN_A)).unwrap_or_revert().unwrap_or_revert();
+    let token_b: ContractHash = storage::read(get_uref(TOKEN_B)).unwrap_or_revert().unwrap_or_revert();
+    let info = (Key::Hash(token_a.value()), Key::Hash(token_b.value()), self_key());
+    runtime::ret(CLValue::from_t(info).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn is_paused() {
+    let paused: bool = storage::read(get_uref(PAUSED)).unwrap_or_revert().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(paused).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn get_reserves() {
     let reserve_a_uref = get_uref(RESERVE_A);
@@ -197,68 +1094,21 @@ pub extern "C" fn get_reserves() {
 
 #[no_mangle]
 pub extern "C" fn call() {
-    let mut entry_points = EntryPoints::new();
-    
-    entry_points.add_entry_point(EntryPoint::new(
-        String::from("init"),
-        vec![
-            Parameter::new("token_a", Key::cl_type()),
-            Parameter::new("token_b", Key::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-        EntryPointPayment::Caller,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        String::from("add_liquidity"),
-        vec![
-            Parameter::new("amount_a", U256::cl_type()),
-            Parameter::new("amount_b", U256::cl_type()),
-        ],
-        CLType::Bool,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-        EntryPointPayment::Caller,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        String::from("swap_a_to_b"),
-        vec![
-            Parameter::new("amount_in", U256::cl_type()),
-            Parameter::new("min_amount_out", U256::cl_type()),
-        ],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-        EntryPointPayment::Caller,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        String::from("swap_b_to_a"),
-        vec![
-            Parameter::new("amount_in", U256::cl_type()),
-            Parameter::new("min_amount_out", U256::cl_type()),
-        ],
-        U256::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-        EntryPointPayment::Caller,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        String::from("get_reserves"),
-        vec![],
-        CLType::Tuple2([
-            alloc::boxed::Box::new(U256::cl_type()),
-            alloc::boxed::Box::new(U256::cl_type()),
-        ]),
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-        EntryPointPayment::Caller,
-    ));
-
-    let (contract_hash, _) = storage::new_contract(entry_points, None, None, None, None);
-    runtime::put_key("simple_amm", contract_hash.into());
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        String::from(ADMIN),
+        storage::new_uref(Key::from(runtime::get_caller())).into(),
+    );
+    named_keys.insert(String::from(factory::POOL_COUNT), storage::new_uref(0u64).into());
+
+    let (contract_hash, _) = storage::new_contract(
+        entry_points::factory_entry_points(),
+        Some(named_keys),
+        None,
+        None,
+        None,
+    );
+    runtime::put_key("simple_amm_factory", contract_hash.into());
+
+    runtime::call_contract::<()>(contract_hash, "init_factory", RuntimeArgs::new());
 }
@@ -0,0 +1,136 @@
+//! Pricing math shared by the swap paths and the quote entry points, so previews always match
+//! execution.
+use casper_types::{U256, U512};
+
+/// Denominator for every basis-point value (fee tier and protocol share).
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Fixed-point scale of the prices returned by [`spot_price`] (18 decimals).
+pub fn price_scale() -> U256 {
+    U256::exp10(18)
+}
+
+/// Output amount for an exact `amount_in`, after the pool fee.
+pub fn get_amount_out(amount_in: U256, reserve_in: U256, reserve_out: U256, fee_bps: u32) -> U256 {
+    let amount_in_with_fee = amount_in * U256::from(BPS_DENOMINATOR - fee_bps);
+    let numerator = amount_in_with_fee * reserve_out;
+    let denominator = (reserve_in * U256::from(BPS_DENOMINATOR)) + amount_in_with_fee;
+    if denominator.is_zero() {
+        return U256::zero();
+    }
+    numerator / denominator
+}
+
+/// Input amount required for an exact `amount_out`, after the pool fee. Rounds up so the pool
+/// never receives less than the constant product requires.
+///
+/// Returns `None` if the pool cannot pay out `amount_out`.
+pub fn get_amount_in(
+    amount_out: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    fee_bps: u32,
+) -> Option<U256> {
+    if amount_out >= reserve_out {
+        return None;
+    }
+    let numerator = reserve_in * amount_out * U256::from(BPS_DENOMINATOR);
+    let denominator = (reserve_out - amount_out) * U256::from(BPS_DENOMINATOR - fee_bps);
+    Some((numerator / denominator) + U256::one())
+}
+
+/// Marginal price of one unit of the base token in units of the quote token, scaled by
+/// [`price_scale`]. Zero while the pool is empty.
+pub fn spot_price(reserve_base: U256, reserve_quote: U256) -> U256 {
+    if reserve_base.is_zero() {
+        return U256::zero();
+    }
+    reserve_quote * price_scale() / reserve_base
+}
+
+/// Amount of the other token matching `amount` at the current reserve ratio.
+pub fn quote(amount: U256, reserve_from: U256, reserve_to: U256) -> U256 {
+    if reserve_from.is_zero() {
+        return U256::zero();
+    }
+    amount * reserve_to / reserve_from
+}
+
+/// Largest pair of deposits, bounded by the desired amounts, that keeps the reserve ratio. An
+/// empty pool takes both amounts as given.
+pub fn optimal_liquidity(
+    amount_a_desired: U256,
+    amount_b_desired: U256,
+    reserve_a: U256,
+    reserve_b: U256,
+) -> (U256, U256) {
+    if reserve_a.is_zero() && reserve_b.is_zero() {
+        return (amount_a_desired, amount_b_desired);
+    }
+    let amount_b_optimal = quote(amount_a_desired, reserve_a, reserve_b);
+    if amount_b_optimal <= amount_b_desired {
+        (amount_a_desired, amount_b_optimal)
+    } else {
+        (
+            quote(amount_b_desired, reserve_b, reserve_a),
+            amount_b_desired,
+        )
+    }
+}
+
+/// Constant-product check for flash swaps: the balances after the callback, with the fee taken
+/// off whatever came in, must keep `k` at least at its value before the swap.
+pub fn invariant_holds(
+    balance_a: U256,
+    balance_b: U256,
+    amount_a_in: U256,
+    amount_b_in: U256,
+    reserve_a: U256,
+    reserve_b: U256,
+    fee_bps: u32,
+) -> bool {
+    let denominator = U256::from(BPS_DENOMINATOR);
+    let fee = U256::from(fee_bps);
+    let adjusted_a = balance_a * denominator - amount_a_in * fee;
+    let adjusted_b = balance_b * denominator - amount_b_in * fee;
+    adjusted_a * adjusted_b >= reserve_a * reserve_b * denominator * denominator
+}
+
+/// Widens `value` to 512 bits, for products that may not fit 256.
+pub fn widen(value: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    value.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+/// Narrows `value` back to 256 bits, or `None` if it does not fit.
+pub fn narrow(value: U512) -> Option<U256> {
+    let mut bytes = [0u8; 64];
+    value.to_little_endian(&mut bytes);
+    if bytes[32..].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    Some(U256::from_little_endian(&bytes[..32]))
+}
+
+/// `a * b / denominator` with a 512-bit intermediate product, rounded down.
+pub fn mul_div(a: U256, b: U256, denominator: U256) -> Option<U256> {
+    if denominator.is_zero() {
+        return None;
+    }
+    narrow(widen(a) * widen(b) / widen(denominator))
+}
+
+/// `a * b / denominator` with a 512-bit intermediate product, rounded up.
+pub fn mul_div_rounding_up(a: U256, b: U256, denominator: U256) -> Option<U256> {
+    if denominator.is_zero() {
+        return None;
+    }
+    let product = widen(a) * widen(b);
+    let denominator = widen(denominator);
+    let mut quotient = product / denominator;
+    if !(product % denominator).is_zero() {
+        quotient += U512::one();
+    }
+    narrow(quotient)
+}
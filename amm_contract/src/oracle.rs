@@ -0,0 +1,148 @@
+//! Uniswap-v2 style price accumulators. Each reserve change first adds the spot price that held
+//! since the last change, weighted by the elapsed block time, so the average price over any
+//! window can be derived from two readings and cannot be moved within a single block.
+use alloc::string::ToString;
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{ApiError, CLValue, U256};
+
+use crate::{get_uref, read_reserves, spot_price, ERROR_ORACLE_WINDOW};
+
+const PRICE_A_CUMULATIVE: &str = "price_a_cumulative";
+const PRICE_B_CUMULATIVE: &str = "price_b_cumulative";
+const BLOCK_TIMESTAMP_LAST: &str = "block_timestamp_last";
+const OBSERVATIONS: &str = "observations";
+const OBSERVATION_COUNT: &str = "observation_count";
+
+/// Accumulator reading: `(timestamp, price_a_cumulative, price_b_cumulative)`.
+type Observation = (u64, U256, U256);
+
+fn now() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+/// Creates the accumulators and records the first observation at install time.
+pub(crate) fn init() {
+    let timestamp = now();
+    runtime::put_key(PRICE_A_CUMULATIVE, storage::new_uref(U256::zero()).into());
+    runtime::put_key(PRICE_B_CUMULATIVE, storage::new_uref(U256::zero()).into());
+    runtime::put_key(BLOCK_TIMESTAMP_LAST, storage::new_uref(timestamp).into());
+    runtime::put_key(OBSERVATION_COUNT, storage::new_uref(0u64).into());
+    storage::new_dictionary(OBSERVATIONS).unwrap_or_revert();
+    record((timestamp, U256::zero(), U256::zero()));
+}
+
+fn record(observation: Observation) {
+    let count_uref = get_uref(OBSERVATION_COUNT);
+    let count: u64 = storage::read(count_uref).unwrap_or_revert().unwrap_or(0);
+    storage::dictionary_put(get_uref(OBSERVATIONS), &count.to_string(), observation);
+    storage::write(count_uref, count + 1);
+}
+
+fn read_observation(index: u64) -> Observation {
+    storage::dictionary_get(get_uref(OBSERVATIONS), &index.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Accumulators as of `timestamp`, extending the stored values with the price implied by the
+/// given reserves since the last update. Sums wrap on overflow; only differences are meaningful.
+fn cumulative_at(timestamp: u64, reserve_a: U256, reserve_b: U256) -> Observation {
+    let mut price_a: U256 = storage::read(get_uref(PRICE_A_CUMULATIVE))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let mut price_b: U256 = storage::read(get_uref(PRICE_B_CUMULATIVE))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    let last: u64 = storage::read(get_uref(BLOCK_TIMESTAMP_LAST))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+
+    let elapsed = U256::from(timestamp.saturating_sub(last));
+    if !elapsed.is_zero() && !reserve_a.is_zero() && !reserve_b.is_zero() {
+        price_a = price_a
+            .overflowing_add(spot_price(reserve_a, reserve_b).overflowing_mul(elapsed).0)
+            .0;
+        price_b = price_b
+            .overflowing_add(spot_price(reserve_b, reserve_a).overflowing_mul(elapsed).0)
+            .0;
+    }
+    (timestamp, price_a, price_b)
+}
+
+/// Folds the reserves that are about to be replaced into the accumulators. Must run before every
+/// reserve write; at most one observation is recorded per block.
+pub(crate) fn update(reserve_a: U256, reserve_b: U256) {
+    let timestamp = now();
+    let last: u64 = storage::read(get_uref(BLOCK_TIMESTAMP_LAST))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    if timestamp <= last {
+        return;
+    }
+
+    let observation = cumulative_at(timestamp, reserve_a, reserve_b);
+    let (_, price_a, price_b) = observation;
+    storage::write(get_uref(PRICE_A_CUMULATIVE), price_a);
+    storage::write(get_uref(PRICE_B_CUMULATIVE), price_b);
+    storage::write(get_uref(BLOCK_TIMESTAMP_LAST), timestamp);
+    record(observation);
+}
+
+/// Latest observation taken at or before `target`, if any.
+fn observation_before(target: u64) -> Option<Observation> {
+    let count: u64 = storage::read(get_uref(OBSERVATION_COUNT))
+        .unwrap_or_revert()
+        .unwrap_or(0);
+
+    let (mut low, mut high) = (0u64, count);
+    let mut found = None;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let observation = read_observation(mid);
+        if observation.0 <= target {
+            found = Some(observation);
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    found
+}
+
+/// Returns `(current_cumulative_a, current_cumulative_b, timestamp)`, extended to the current
+/// block time, for callers that keep their own snapshots.
+#[no_mangle]
+pub extern "C" fn get_cumulative_prices() {
+    let (reserve_a, reserve_b) = read_reserves();
+    let (timestamp, price_a, price_b) = cumulative_at(now(), reserve_a, reserve_b);
+
+    runtime::ret(CLValue::from_t((price_a, price_b, timestamp)).unwrap_or_revert());
+}
+
+/// Returns `(twap_a_in_b, twap_b_in_a)` over at least the last `window` milliseconds, each scaled
+/// by 10^18. The average runs from the latest observation at or before `now - window`, so it can
+/// cover slightly more than `window`. Reverts if the pool has no observation that old.
+#[no_mangle]
+pub extern "C" fn consult() {
+    let window: u64 = runtime::get_named_arg("window");
+    let timestamp = now();
+
+    let (start, start_a, start_b) = timestamp
+        .checked_sub(window)
+        .and_then(observation_before)
+        .unwrap_or_revert_with(ApiError::User(ERROR_ORACLE_WINDOW));
+    if start >= timestamp {
+        runtime::revert(ApiError::User(ERROR_ORACLE_WINDOW));
+    }
+
+    let (reserve_a, reserve_b) = read_reserves();
+    let (_, end_a, end_b) = cumulative_at(timestamp, reserve_a, reserve_b);
+    let elapsed = U256::from(timestamp - start);
+
+    let twap = (
+        end_a.overflowing_sub(start_a).0 / elapsed,
+        end_b.overflowing_sub(start_b).0 / elapsed,
+    );
+    runtime::ret(CLValue::from_t(twap).unwrap_or_revert());
+}
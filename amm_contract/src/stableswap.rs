@@ -0,0 +1,177 @@
+//! StableSwap (Curve) invariant for two-token pools of pegged assets:
+//! `A * n^n * (x + y) + D = A * n^n * D + D^(n+1) / (n^n * x * y)` with `n = 2`. Close to the peg
+//! it trades almost like `x + y = D`, far from it like `x * y = k`. `amp` (`A`) sets how wide the
+//! flat part is. Both tokens are assumed to use the same number of decimals.
+//!
+//! `D` and the new balance after a trade are solved by Newton iteration. Every function returns
+//! `None` if the iteration does not converge or an intermediate value overflows.
+use casper_types::U256;
+
+use crate::math::{mul_div, price_scale, BPS_DENOMINATOR};
+
+/// `n^n` for two tokens.
+const N_COINS_POW: u64 = 4;
+const MAX_ITERATIONS: usize = 255;
+
+fn converged(value: U256, previous: U256) -> bool {
+    let difference = if value > previous {
+        value - previous
+    } else {
+        previous - value
+    };
+    difference <= U256::one()
+}
+
+/// `A * n^n`.
+fn ann(amp: u64) -> U256 {
+    U256::from(amp) * U256::from(N_COINS_POW)
+}
+
+/// The invariant `D` of balances `x` and `y`: their sum at the peg.
+pub fn get_d(x: U256, y: U256, amp: u64) -> Option<U256> {
+    let sum = x.checked_add(y)?;
+    if sum.is_zero() {
+        return Some(U256::zero());
+    }
+    if x.is_zero() || y.is_zero() {
+        return None;
+    }
+    let ann = ann(amp);
+
+    let mut d = sum;
+    for _ in 0..MAX_ITERATIONS {
+        // D^(n+1) / (n^n * x * y)
+        let d_p = mul_div(mul_div(d, d, x * 2)?, d, y * 2)?;
+        let previous = d;
+        let numerator = ann.checked_mul(sum)?.checked_add(d_p * 2)?;
+        let denominator = (ann - 1).checked_mul(d)?.checked_add(d_p * 3)?;
+        d = mul_div(numerator, d, denominator)?;
+        if converged(d, previous) {
+            return Some(d);
+        }
+    }
+    None
+}
+
+/// Balance of one token that keeps the invariant at `d` when the other token's balance is `x`.
+pub fn get_y(x: U256, d: U256, amp: u64) -> Option<U256> {
+    if x.is_zero() {
+        return None;
+    }
+    let ann = ann(amp);
+    // y^2 + (b - D) * y = c, with c = D^(n+1) / (n^n * x * Ann) and b = x + D / Ann.
+    let c = mul_div(mul_div(d, d, x * 2)?, d, ann * 2)?;
+    let b = x.checked_add(d / ann)?;
+
+    let mut y = d;
+    for _ in 0..MAX_ITERATIONS {
+        let previous = y;
+        let numerator = y.checked_mul(y)?.checked_add(c)?;
+        let denominator = (y * 2).checked_add(b)?.checked_sub(d)?;
+        y = numerator / denominator;
+        if converged(y, previous) {
+            return Some(y);
+        }
+    }
+    None
+}
+
+/// Output amount for an exact `amount_in`, with the pool fee taken off the input as on the
+/// constant-product curve. Rounds down.
+pub fn get_amount_out(
+    amount_in: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    fee_bps: u32,
+    amp: u64,
+) -> Option<U256> {
+    let d = get_d(reserve_in, reserve_out, amp)?;
+    let amount_in_with_fee = mul_div(
+        amount_in,
+        U256::from(BPS_DENOMINATOR - fee_bps),
+        U256::from(BPS_DENOMINATOR),
+    )?;
+    let new_reserve_out = get_y(reserve_in.checked_add(amount_in_with_fee)?, d, amp)?;
+    // One unit is held back against the rounding of the iteration.
+    Some(
+        reserve_out
+            .saturating_sub(new_reserve_out)
+            .saturating_sub(U256::one()),
+    )
+}
+
+/// Input amount required for an exact `amount_out`, fee included. Rounds up.
+///
+/// Returns `None` if the pool cannot pay out `amount_out`.
+pub fn get_amount_in(
+    amount_out: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    fee_bps: u32,
+    amp: u64,
+) -> Option<U256> {
+    if amount_out >= reserve_out {
+        return None;
+    }
+    let d = get_d(reserve_in, reserve_out, amp)?;
+    let new_reserve_in = get_y(reserve_out - amount_out, d, amp)?;
+    let amount_in_with_fee = new_reserve_in.checked_sub(reserve_in)? + U256::one();
+    Some(
+        mul_div(
+            amount_in_with_fee,
+            U256::from(BPS_DENOMINATOR),
+            U256::from(BPS_DENOMINATOR - fee_bps),
+        )? + U256::one(),
+    )
+}
+
+/// Marginal price of one unit of the base token in units of the quote token, scaled by
+/// [`price_scale`]: the slope of the invariant at the current balances. Zero while the pool is
+/// empty.
+pub fn spot_price(reserve_base: U256, reserve_quote: U256, amp: u64) -> U256 {
+    if reserve_base.is_zero() || reserve_quote.is_zero() {
+        return U256::zero();
+    }
+    let price = || {
+        let d = get_d(reserve_base, reserve_quote, amp)?;
+        let ann = ann(amp).checked_mul(price_scale())?;
+        // D^(n+1) / (n^n * x^2 * y) and D^(n+1) / (n^n * x * y^2), scaled.
+        let d_over_base = mul_div(d, d, reserve_base * 2)?;
+        let d_over_quote = mul_div(d, d, reserve_quote * 2)?;
+        let base_term = mul_div(
+            mul_div(d_over_base, d, reserve_base * 2)?,
+            price_scale(),
+            reserve_quote,
+        )?;
+        let quote_term = mul_div(
+            mul_div(d_over_quote, d, reserve_quote * 2)?,
+            price_scale(),
+            reserve_base,
+        )?;
+        mul_div(
+            ann.checked_add(base_term)?,
+            price_scale(),
+            ann.checked_add(quote_term)?,
+        )
+    };
+    price().unwrap_or_default()
+}
+
+/// StableSwap check for flash swaps: the `(a, b)` balances after the callback, with the fee taken
+/// off whatever came in, must keep `D` at least at its value for the reserves before the swap.
+pub fn invariant_holds(
+    balances: (U256, U256),
+    amounts_in: (U256, U256),
+    reserves: (U256, U256),
+    fee_bps: u32,
+    amp: u64,
+) -> bool {
+    let fee =
+        |amount_in: U256| mul_div(amount_in, U256::from(fee_bps), U256::from(BPS_DENOMINATOR));
+    let check = || {
+        let adjusted_a = balances.0.checked_sub(fee(amounts_in.0)?)?;
+        let adjusted_b = balances.1.checked_sub(fee(amounts_in.1)?)?;
+        Some(get_d(adjusted_a, adjusted_b, amp)? >= get_d(reserves.0, reserves.1, amp)?)
+    };
+    check().unwrap_or(false)
+}
@@ -0,0 +1,55 @@
+//! Session code used by the WCSPR and pool CSPR swap tests. Creates a purse, funds it with `motes`
+//! from the account's main purse, keeps it under the account named key `purse_name` so tests can
+//! read its balance, and calls `entry_point` on `target` with `call_args` plus that purse as `purse`.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes},
+    contracts::ContractHash,
+    Key, RuntimeArgs, U256, U512,
+};
+
+const ARG_TARGET: &str = "target";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ARG_MOTES: &str = "motes";
+const ARG_PURSE_NAME: &str = "purse_name";
+const ARG_CALL_ARGS: &str = "call_args";
+const ARG_PURSE: &str = "purse";
+
+/// WCSPR entry points that return nothing; the pool swaps return the output amount.
+const UNIT_ENTRY_POINTS: [&str; 2] = ["deposit", "withdraw"];
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let target: Key = runtime::get_named_arg(ARG_TARGET);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+    let motes: U512 = runtime::get_named_arg(ARG_MOTES);
+    let purse_name: String = runtime::get_named_arg(ARG_PURSE_NAME);
+    let call_args: Bytes = runtime::get_named_arg(ARG_CALL_ARGS);
+
+    let purse = system::create_purse();
+    if !motes.is_zero() {
+        system::transfer_from_purse_to_purse(account::get_main_purse(), purse, motes, None)
+            .unwrap_or_revert();
+    }
+    runtime::put_key(&purse_name, purse.into());
+
+    let mut args: RuntimeArgs =
+        bytesrepr::deserialize_from_slice(&call_args[..]).unwrap_or_revert();
+    args.insert(ARG_PURSE, purse).unwrap_or_revert();
+
+    let target = ContractHash::new(target.into_hash_addr().unwrap_or_revert());
+    if UNIT_ENTRY_POINTS.contains(&entry_point.as_str()) {
+        runtime::call_contract::<()>(target, &entry_point, args);
+    } else {
+        runtime::call_contract::<U256>(target, &entry_point, args);
+    }
+}
@@ -0,0 +1,83 @@
+//! Flash-swap callee used by the AMM tests. Its callback repays the pool that called it with its
+//! own balance, in the token and amount encoded in the callback `data`, so tests can choose
+//! between a full and a short repayment.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes},
+    contracts::{ContractHash, ContractPackageHash},
+    runtime_args, CLType, CLTyped, EntityEntryPoint as EntryPoint, EntryPointAccess,
+    EntryPointPayment, EntryPointType, EntryPoints, Key, Parameter, U256,
+};
+
+const CONTRACT_KEY_NAME: &str = "flash_borrower";
+const PACKAGE_KEY_NAME: &str = "flash_borrower_package";
+
+const ARG_SENDER: &str = "sender";
+const ARG_AMOUNT_A_OUT: &str = "amount_a_out";
+const ARG_AMOUNT_B_OUT: &str = "amount_b_out";
+const ARG_DATA: &str = "data";
+
+/// Package key of the immediate caller, i.e. the pool running the flash swap.
+fn calling_pool() -> Key {
+    runtime::get_immediate_caller()
+        .unwrap_or_revert()
+        .get_field_by_index(2)
+        .unwrap_or_revert()
+        .to_t::<Option<ContractPackageHash>>()
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+        .into()
+}
+
+/// Transfers the `(token, amount)` encoded in `data` back to the calling pool.
+#[no_mangle]
+pub extern "C" fn flash_swap_callback() {
+    let data: Bytes = runtime::get_named_arg(ARG_DATA);
+    let (token, repay): (Key, U256) =
+        bytesrepr::deserialize_from_slice(&data[..]).unwrap_or_revert();
+
+    runtime::call_contract::<()>(
+        ContractHash::new(token.into_hash_addr().unwrap_or_revert()),
+        "transfer",
+        runtime_args! {
+            "recipient" => calling_pool(),
+            "amount" => repay,
+        },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "flash_swap_callback".to_string(),
+        vec![
+            Parameter::new(ARG_SENDER, Key::cl_type()),
+            Parameter::new(ARG_AMOUNT_A_OUT, U256::cl_type()),
+            Parameter::new(ARG_AMOUNT_B_OUT, U256::cl_type()),
+            Parameter::new(ARG_DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    let (contract_hash, _) = storage::new_contract(
+        entry_points,
+        None,
+        Some(PACKAGE_KEY_NAME.to_string()),
+        None,
+        None,
+    );
+    runtime::put_key(CONTRACT_KEY_NAME, Key::Hash(contract_hash.value()));
+}
@@ -0,0 +1,242 @@
+//! Minimal CEP-18 style token used by the AMM tests. Once armed with a pool, its `transfer_from`
+//! calls back into that pool's `swap_a_to_b` before moving any balance, imitating a malicious
+//! token trying to re-enter at stale reserves.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::ToBytes,
+    contracts::{ContractHash, ContractPackageHash},
+    runtime_args, ApiError, CLType, CLTyped, CLValue, EntityEntryPoint as EntryPoint,
+    EntryPointAccess, EntryPointPayment, EntryPointType, EntryPoints, Key, NamedKeys, Parameter,
+    U256,
+};
+
+const BALANCES: &str = "balances";
+const TARGET: &str = "target";
+
+const ARG_NAME: &str = "name";
+const ARG_TOTAL_SUPPLY: &str = "total_supply";
+const ARG_ADDRESS: &str = "address";
+const ARG_OWNER: &str = "owner";
+const ARG_RECIPIENT: &str = "recipient";
+const ARG_SPENDER: &str = "spender";
+const ARG_AMOUNT: &str = "amount";
+const ARG_TARGET: &str = "target";
+
+const ERROR_INSUFFICIENT_BALANCE: u16 = 1;
+
+fn balance_key(address: Key) -> String {
+    let mut s = String::new();
+    for byte in runtime::blake2b(address.to_bytes().unwrap_or_revert()) {
+        s.push_str(&format!("{:02x}", byte));
+    }
+    s
+}
+
+fn read_balance(address: Key) -> U256 {
+    let balances = runtime::get_key(BALANCES)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::dictionary_get(balances, &balance_key(address))
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_balance(address: Key, amount: U256) {
+    let balances = runtime::get_key(BALANCES)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::dictionary_put(balances, &balance_key(address), amount);
+}
+
+/// Moves `amount` from `from` to `to`, reverting if `from` holds less, even when both are the
+/// same address, so a transfer to the sender itself cannot hide a missing balance.
+fn move_balance(from: Key, to: Key, amount: U256) {
+    let from_balance = read_balance(from)
+        .checked_sub(amount)
+        .unwrap_or_revert_with(ApiError::User(ERROR_INSUFFICIENT_BALANCE));
+    write_balance(from, from_balance);
+    write_balance(to, read_balance(to) + amount);
+}
+
+/// Key of the immediate caller, matching how the pool identifies itself to tokens.
+fn immediate_caller() -> Key {
+    let caller_info = runtime::get_immediate_caller().unwrap_or_revert();
+    match caller_info.kind() {
+        0 => caller_info
+            .get_field_by_index(0)
+            .unwrap_or_revert()
+            .to_t::<Option<AccountHash>>()
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+            .into(),
+        _ => caller_info
+            .get_field_by_index(2)
+            .unwrap_or_revert()
+            .to_t::<Option<ContractPackageHash>>()
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+            .into(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn init() {
+    storage::new_dictionary(BALANCES).unwrap_or_revert();
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    let total_supply: U256 = runtime::get_named_arg(ARG_TOTAL_SUPPLY);
+    write_balance(owner, total_supply);
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Key = runtime::get_named_arg(ARG_ADDRESS);
+    runtime::ret(CLValue::from_t(read_balance(address)).unwrap_or_revert());
+}
+
+/// Allowances are not tracked; every spender may move any balance.
+#[no_mangle]
+pub extern "C" fn approve() {}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Key = runtime::get_named_arg(ARG_RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    move_balance(immediate_caller(), recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    let recipient: Key = runtime::get_named_arg(ARG_RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let target_uref = runtime::get_key(TARGET)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    let target: Option<Key> = storage::read(target_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    if let Some(pool) = target {
+        storage::write(target_uref, Option::<Key>::None);
+        runtime::call_contract::<U256>(
+            ContractHash::new(pool.into_hash_addr().unwrap_or_revert()),
+            "swap_a_to_b",
+            runtime_args! {
+                "amount_in" => U256::one(),
+                "min_amount_out" => U256::zero(),
+            },
+        );
+    }
+
+    move_balance(owner, recipient, amount);
+}
+
+/// Makes the next `transfer_from` re-enter `target`.
+#[no_mangle]
+pub extern "C" fn arm() {
+    let target: Key = runtime::get_named_arg(ARG_TARGET);
+    let target_uref = runtime::get_key(TARGET)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::write(target_uref, Some(target));
+}
+
+fn entry_point(name: &str, params: Vec<Parameter>, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        name.to_string(),
+        params,
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let name: String = runtime::get_named_arg(ARG_NAME);
+    let total_supply: U256 = runtime::get_named_arg(ARG_TOTAL_SUPPLY);
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(entry_point(
+        "init",
+        vec![
+            Parameter::new(ARG_OWNER, Key::cl_type()),
+            Parameter::new(ARG_TOTAL_SUPPLY, U256::cl_type()),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(entry_point(
+        "balance_of",
+        vec![Parameter::new(ARG_ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+    ));
+    entry_points.add_entry_point(entry_point(
+        "approve",
+        vec![
+            Parameter::new(ARG_SPENDER, Key::cl_type()),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(entry_point(
+        "transfer",
+        vec![
+            Parameter::new(ARG_RECIPIENT, Key::cl_type()),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(entry_point(
+        "transfer_from",
+        vec![
+            Parameter::new(ARG_OWNER, Key::cl_type()),
+            Parameter::new(ARG_RECIPIENT, Key::cl_type()),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(entry_point(
+        "arm",
+        vec![Parameter::new(ARG_TARGET, Key::cl_type())],
+        CLType::Unit,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        TARGET.to_string(),
+        storage::new_uref(Option::<Key>::None).into(),
+    );
+
+    let (contract_hash, _) =
+        storage::new_contract(entry_points, Some(named_keys), None, None, None);
+    runtime::put_key(&name, Key::Hash(contract_hash.value()));
+
+    runtime::call_contract::<()>(
+        contract_hash,
+        "init",
+        runtime_args! {
+            ARG_OWNER => Key::from(runtime::get_caller()),
+            ARG_TOTAL_SUPPLY => total_supply,
+        },
+    );
+}
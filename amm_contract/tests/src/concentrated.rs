@@ -0,0 +1,165 @@
+use crate::utility::{
+    constants::{ERROR_INSUFFICIENT_LIQUIDITY, ERROR_INVALID_TICK, ERROR_PAUSED, LIQUIDITY_AMOUNT},
+    installer_request_builders::{
+        assert_user_error, call_view, query_stored_value, setup_concentrated, token_balance,
+        TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, AddressableEntityHash, Key, RuntimeArgs, U256};
+
+fn mint_position_request(
+    pool: AddressableEntityHash,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: U256,
+) -> ExecuteRequestBuilder {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "mint_position",
+        runtime_args! {
+            "tick_lower" => tick_lower,
+            "tick_upper" => tick_upper,
+            "liquidity" => liquidity,
+        },
+    )
+}
+
+fn swap_a_to_b(builder: &mut LmdbWasmTestBuilder, pool: AddressableEntityHash, amount_in: U256) {
+    let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "swap_a_to_b",
+        runtime_args! {
+            "amount_in" => amount_in,
+            "min_amount_out" => U256::zero(),
+        },
+    )
+    .build();
+    builder.exec(swap_request);
+}
+
+#[test]
+fn should_swap_within_position_range() {
+    let (mut builder, test_context) = setup_concentrated();
+    let TestContext { pool, .. } = test_context;
+
+    let liquidity = U256::from(LIQUIDITY_AMOUNT) * 1_000;
+    builder
+        .exec(mint_position_request(pool, -600, 600, liquidity).build())
+        .expect_success()
+        .commit();
+    let active: U256 = query_stored_value(&builder, pool, "liquidity");
+    assert_eq!(active, liquidity);
+
+    swap_a_to_b(&mut builder, pool, U256::from(100_000u64));
+    builder.expect_success().commit();
+
+    let sqrt_price: U256 = query_stored_value(&builder, pool, "sqrt_price_x96");
+    let tick: i32 = query_stored_value(&builder, pool, "tick");
+    let active: U256 = query_stored_value(&builder, pool, "liquidity");
+    let fee_growth_a: U256 = query_stored_value(&builder, pool, "fee_growth_global_a");
+    assert!(sqrt_price < U256::one() << 96);
+    assert!((-600..0).contains(&tick), "{tick}");
+    assert_eq!(active, liquidity);
+    assert!(!fee_growth_a.is_zero());
+}
+
+#[test]
+fn should_keep_out_of_range_position_inactive() {
+    let (mut builder, test_context) = setup_concentrated();
+    let TestContext { pool, .. } = test_context;
+
+    let liquidity = U256::from(LIQUIDITY_AMOUNT);
+    builder
+        .exec(mint_position_request(pool, 600, 1_200, liquidity).build())
+        .expect_success()
+        .commit();
+
+    let active: U256 = query_stored_value(&builder, pool, "liquidity");
+    assert!(active.is_zero());
+
+    swap_a_to_b(&mut builder, pool, U256::from(1_000u64));
+    builder.expect_failure();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_LIQUIDITY);
+}
+
+#[test]
+fn should_reject_misaligned_ticks() {
+    let (mut builder, test_context) = setup_concentrated();
+    let TestContext { pool, .. } = test_context;
+
+    builder
+        .exec(mint_position_request(pool, -600, 601, U256::from(LIQUIDITY_AMOUNT)).build())
+        .expect_failure();
+    assert_user_error(&builder, ERROR_INVALID_TICK);
+}
+
+#[test]
+fn should_let_positions_exit_while_paused() {
+    let (mut builder, test_context) = setup_concentrated();
+    let TestContext {
+        pool,
+        token_a,
+        token_b,
+        ..
+    } = test_context;
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let liquidity = U256::from(LIQUIDITY_AMOUNT);
+    builder
+        .exec(mint_position_request(pool, -600, 600, liquidity).build())
+        .expect_success()
+        .commit();
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "pause",
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    builder
+        .exec(mint_position_request(pool, -600, 600, liquidity).build())
+        .expect_failure();
+    assert_user_error(&builder, ERROR_PAUSED);
+
+    let burned: (U256, U256) = call_view(
+        &mut builder,
+        pool,
+        "burn_position",
+        runtime_args! {
+            "tick_lower" => -600i32,
+            "tick_upper" => 600i32,
+            "liquidity" => liquidity,
+        },
+    );
+    let active: U256 = query_stored_value(&builder, pool, "liquidity");
+    assert!(active.is_zero());
+
+    let balance_a = token_balance(&builder, token_a, owner);
+    let balance_b = token_balance(&builder, token_b, owner);
+    let collected: (U256, U256) = call_view(
+        &mut builder,
+        pool,
+        "collect",
+        runtime_args! {
+            "tick_lower" => -600i32,
+            "tick_upper" => 600i32,
+        },
+    );
+    assert_eq!(collected, burned);
+    assert_eq!(
+        token_balance(&builder, token_a, owner),
+        balance_a + burned.0
+    );
+    assert_eq!(
+        token_balance(&builder, token_b, owner),
+        balance_b + burned.1
+    );
+}
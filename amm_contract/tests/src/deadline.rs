@@ -0,0 +1,132 @@
+use crate::utility::{
+    constants::{ERROR_EXPIRED, LIQUIDITY_AMOUNT},
+    installer_request_builders::{
+        add_liquidity, assert_user_error, query_stored_value, setup, setup_concentrated,
+        TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequest, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, AddressableEntityHash, Key, RuntimeArgs, U256};
+
+const BLOCK_TIME: u64 = 10_000;
+
+fn make_swap_request(pool: AddressableEntityHash, deadline: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "swap_a_to_b",
+        runtime_args! {
+            "amount_in" => U256::from(1_000u64),
+            "min_amount_out" => U256::zero(),
+            "deadline" => deadline,
+        },
+    )
+    .with_block_time(BLOCK_TIME)
+    .build()
+}
+
+#[test]
+fn should_reject_swap_after_deadline() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    builder
+        .exec(make_swap_request(pool, BLOCK_TIME - 1))
+        .expect_failure();
+    assert_user_error(&builder, ERROR_EXPIRED);
+
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    assert_eq!(reserve_a, amount);
+}
+
+#[test]
+fn should_swap_until_deadline() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    builder
+        .exec(make_swap_request(pool, BLOCK_TIME))
+        .expect_success()
+        .commit();
+
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    assert_eq!(reserve_a, amount + U256::from(1_000u64));
+}
+
+/// Calls `entry_point` with `args` and a deadline just before the block time, and asserts it
+/// reverts as expired.
+fn assert_expired(
+    builder: &mut LmdbWasmTestBuilder,
+    pool: AddressableEntityHash,
+    entry_point: &str,
+    mut args: RuntimeArgs,
+) {
+    args.insert("deadline", BLOCK_TIME - 1).unwrap();
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        entry_point,
+        args,
+    )
+    .with_block_time(BLOCK_TIME)
+    .build();
+    builder.exec(request).expect_failure();
+    assert_user_error(builder, ERROR_EXPIRED);
+}
+
+#[test]
+fn should_reject_maintenance_calls_after_deadline() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    assert_expired(&mut builder, pool, "sync", runtime_args! {});
+    assert_expired(
+        &mut builder,
+        pool,
+        "skim",
+        runtime_args! { "to" => Key::Account(*DEFAULT_ACCOUNT_ADDR) },
+    );
+    assert_expired(
+        &mut builder,
+        pool,
+        "collect_protocol_fees",
+        runtime_args! {},
+    );
+}
+
+#[test]
+fn should_reject_position_exits_after_deadline() {
+    let (mut builder, test_context) = setup_concentrated();
+    let TestContext { pool, .. } = test_context;
+
+    assert_expired(
+        &mut builder,
+        pool,
+        "burn_position",
+        runtime_args! {
+            "tick_lower" => -600i32,
+            "tick_upper" => 600i32,
+            "liquidity" => U256::zero(),
+        },
+    );
+    assert_expired(
+        &mut builder,
+        pool,
+        "collect",
+        runtime_args! {
+            "tick_lower" => -600i32,
+            "tick_upper" => 600i32,
+        },
+    );
+}
@@ -0,0 +1,133 @@
+use crate::utility::{
+    constants::LIQUIDITY_AMOUNT,
+    installer_request_builders::{
+        add_liquidity, call_view, get_test_account, pool_key, query_stored_value, setup,
+        token_balance, TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, AddressableEntityHash, Key, RuntimeArgs, U256};
+
+const DONATION_A: u64 = 5_000;
+const DONATION_B: u64 = 7_000;
+
+/// Sends tokens straight to the pool, bypassing its entry points, so its balances drift from the
+/// reserves.
+fn donate(
+    builder: &mut LmdbWasmTestBuilder,
+    token: AddressableEntityHash,
+    pool: Key,
+    amount: U256,
+) {
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        token,
+        "transfer",
+        runtime_args! {
+            "recipient" => pool,
+            "amount" => amount,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+fn setup_with_donations() -> (LmdbWasmTestBuilder, TestContext, Key) {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        pool,
+        token_a,
+        token_b,
+        ..
+    } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    let pool_key = pool_key(&builder, pool);
+    donate(&mut builder, token_a, pool_key, U256::from(DONATION_A));
+    donate(&mut builder, token_b, pool_key, U256::from(DONATION_B));
+
+    (builder, test_context, pool_key)
+}
+
+#[test]
+fn should_skim_donation_to_recipient() {
+    let (mut builder, test_context, pool_key) = setup_with_donations();
+    let TestContext {
+        pool,
+        token_a,
+        token_b,
+        ..
+    } = test_context;
+    let (recipient, _) = get_test_account(1);
+
+    let skimmed: (U256, U256) = call_view(
+        &mut builder,
+        pool,
+        "skim",
+        runtime_args! {
+            "to" => recipient,
+        },
+    );
+    assert_eq!(skimmed, (U256::from(DONATION_A), U256::from(DONATION_B)));
+
+    assert_eq!(
+        token_balance(&builder, token_a, recipient),
+        U256::from(DONATION_A)
+    );
+    assert_eq!(
+        token_balance(&builder, token_b, recipient),
+        U256::from(DONATION_B)
+    );
+
+    // The reserves are untouched and match the balances again.
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!((reserve_a, reserve_b), (amount, amount));
+    assert_eq!(token_balance(&builder, token_a, pool_key), reserve_a);
+    assert_eq!(token_balance(&builder, token_b, pool_key), reserve_b);
+}
+
+#[test]
+fn should_sync_reserves_to_donation() {
+    let (mut builder, test_context, pool_key) = setup_with_donations();
+    let TestContext {
+        pool,
+        token_a,
+        token_b,
+        ..
+    } = test_context;
+
+    let sync_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "sync",
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(sync_request).expect_success().commit();
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_a, amount + DONATION_A);
+    assert_eq!(reserve_b, amount + DONATION_B);
+    assert_eq!(token_balance(&builder, token_a, pool_key), reserve_a);
+    assert_eq!(token_balance(&builder, token_b, pool_key), reserve_b);
+
+    // Nothing is left over to skim once the reserves have absorbed the donation.
+    let (recipient, _) = get_test_account(1);
+    let skimmed: (U256, U256) = call_view(
+        &mut builder,
+        pool,
+        "skim",
+        runtime_args! {
+            "to" => recipient,
+        },
+    );
+    assert_eq!(skimmed, (U256::zero(), U256::zero()));
+}
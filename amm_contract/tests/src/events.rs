@@ -0,0 +1,54 @@
+use crate::utility::{
+    constants::{ERROR_INVALID_EVENTS_MODE, LIQUIDITY_AMOUNT},
+    installer_request_builders::{add_liquidity, setup, TestContext},
+};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{runtime_args, ApiError, EntityAddr, U256};
+
+#[test]
+fn should_emit_sync_and_liquidity_added_on_events_topic() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    let entity_addr = EntityAddr::SmartContract(pool.value());
+    let topics = builder.message_topics(None, entity_addr).unwrap();
+    assert!(topics.iter().any(|(topic_name, _)| topic_name == "events"));
+
+    let exec_result = builder.get_last_exec_result().unwrap();
+    let payloads: Vec<String> = exec_result
+        .messages()
+        .iter()
+        .map(|message| format!("{:?}", message.payload()))
+        .collect();
+    // The reserves are written, emitting `Sync`, before the deposit is reported.
+    assert_eq!(payloads.len(), 2);
+    assert!(payloads[0].contains("Sync"));
+    assert!(payloads[1].contains("LiquidityAdded"));
+}
+
+#[test]
+fn should_reject_invalid_events_mode() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let change_events_mode_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "change_events_mode",
+        runtime_args! {
+            "events_mode" => 9u8,
+        },
+    )
+    .build();
+    builder.exec(change_events_mode_request).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_EVENTS_MODE),
+        "{error:?}"
+    );
+}
@@ -0,0 +1,160 @@
+use crate::utility::{
+    constants::{ERROR_IDENTICAL_TOKENS, ERROR_POOL_EXISTS, TICK_SPACING, TOKEN_C_KEY_NAME},
+    installer_request_builders::{
+        assert_user_error, call_view, install_hostile_token, query_stored_value, setup, TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, AddressableEntityHash, Key, RuntimeArgs, U256};
+
+fn token_key(token: AddressableEntityHash) -> Key {
+    Key::Hash(token.value())
+}
+
+fn create_pool_request(
+    factory: AddressableEntityHash,
+    token_a: AddressableEntityHash,
+    token_b: AddressableEntityHash,
+) -> ExecuteRequestBuilder {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        factory,
+        "create_pool",
+        runtime_args! {
+            "token_a" => token_key(token_a),
+            "token_b" => token_key(token_b),
+        },
+    )
+}
+
+fn get_pool(
+    builder: &mut LmdbWasmTestBuilder,
+    factory: AddressableEntityHash,
+    token_x: AddressableEntityHash,
+    token_y: AddressableEntityHash,
+) -> Option<Key> {
+    call_view(
+        builder,
+        factory,
+        "get_pool",
+        runtime_args! {
+            "token_x" => token_key(token_x),
+            "token_y" => token_key(token_y),
+        },
+    )
+}
+
+#[test]
+fn should_reject_duplicate_pair_in_either_order() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        factory,
+        token_a,
+        token_b,
+        ..
+    } = test_context;
+
+    builder
+        .exec(create_pool_request(factory, token_a, token_b).build())
+        .expect_failure();
+    assert_user_error(&builder, ERROR_POOL_EXISTS);
+
+    builder
+        .exec(create_pool_request(factory, token_b, token_a).build())
+        .expect_failure();
+    assert_user_error(&builder, ERROR_POOL_EXISTS);
+
+    // A concentrated pool shares the registry, so it cannot take the pair either.
+    let create_concentrated_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        factory,
+        "create_concentrated_pool",
+        runtime_args! {
+            "token_a" => token_key(token_b),
+            "token_b" => token_key(token_a),
+            "tick_spacing" => TICK_SPACING,
+            "sqrt_price_x96" => U256::one() << 96,
+        },
+    )
+    .build();
+    builder.exec(create_concentrated_request).expect_failure();
+    assert_user_error(&builder, ERROR_POOL_EXISTS);
+
+    let pool_count: u64 = query_stored_value(&builder, factory, "pool_count");
+    assert_eq!(pool_count, 1);
+}
+
+#[test]
+fn should_reject_identical_tokens() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        factory, token_a, ..
+    } = test_context;
+
+    builder
+        .exec(create_pool_request(factory, token_a, token_a).build())
+        .expect_failure();
+    assert_user_error(&builder, ERROR_IDENTICAL_TOKENS);
+}
+
+#[test]
+fn should_look_up_pool_in_either_order() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        factory,
+        pool,
+        token_a,
+        token_b,
+    } = test_context;
+    let token_c = install_hostile_token(&mut builder, TOKEN_C_KEY_NAME);
+
+    let pool_key = Key::Hash(pool.value());
+    assert_eq!(
+        get_pool(&mut builder, factory, token_a, token_b),
+        Some(pool_key)
+    );
+    assert_eq!(
+        get_pool(&mut builder, factory, token_b, token_a),
+        Some(pool_key)
+    );
+    assert_eq!(get_pool(&mut builder, factory, token_a, token_c), None);
+}
+
+#[test]
+fn should_enumerate_all_pools() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        factory,
+        pool,
+        token_a,
+        token_b,
+    } = test_context;
+    let token_c = install_hostile_token(&mut builder, TOKEN_C_KEY_NAME);
+
+    builder
+        .exec(create_pool_request(factory, token_c, token_a).build())
+        .expect_success()
+        .commit();
+    builder
+        .exec(create_pool_request(factory, token_b, token_c).build())
+        .expect_success()
+        .commit();
+
+    let pool_count: u64 = query_stored_value(&builder, factory, "pool_count");
+    assert_eq!(pool_count, 3);
+
+    let all_pools: Vec<Key> = call_view(&mut builder, factory, "all_pools", RuntimeArgs::new());
+    assert_eq!(all_pools.len(), 3);
+    assert_eq!(all_pools[0], Key::Hash(pool.value()));
+    assert_eq!(
+        get_pool(&mut builder, factory, token_a, token_c),
+        Some(all_pools[1])
+    );
+    assert_eq!(
+        get_pool(&mut builder, factory, token_c, token_b),
+        Some(all_pools[2])
+    );
+    assert_ne!(all_pools[1], all_pools[2]);
+}
@@ -0,0 +1,169 @@
+use crate::utility::{
+    constants::{ERROR_INVALID_FEE, ERROR_NOT_ADMIN, LIQUIDITY_AMOUNT},
+    installer_request_builders::{
+        add_liquidity, assert_user_error, get_test_account, pool_key, query_stored_value, setup,
+        setup_with_fee, swap_a_to_b, token_balance, TestContext,
+    },
+};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+/// Constant-product output after a `fee_bps` fee, as the pool computes it.
+fn expected_amount_out(amount_in: U256, reserve_in: U256, reserve_out: U256, fee_bps: u32) -> U256 {
+    let amount_in_with_fee = amount_in * U256::from(10_000 - fee_bps);
+    amount_in_with_fee * reserve_out / (reserve_in * U256::from(10_000u32) + amount_in_with_fee)
+}
+
+#[test]
+fn should_charge_fee_tier_set_at_creation() {
+    let (mut builder, test_context) = setup_with_fee(100);
+    let TestContext { pool, .. } = test_context;
+
+    let fee_bps: u32 = query_stored_value(&builder, pool, "fee_bps");
+    assert_eq!(fee_bps, 100);
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    let amount_in = U256::from(100_000u64);
+    swap_a_to_b(&mut builder, pool, amount_in);
+
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(
+        amount - reserve_b,
+        expected_amount_out(amount_in, amount, amount, 100)
+    );
+}
+
+#[test]
+fn should_reject_fee_tier_of_whole_input() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        factory,
+        token_a,
+        pool,
+        ..
+    } = test_context;
+
+    // A second pool for another pair, so the only failure can be the fee tier.
+    let create_pool_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        factory,
+        "create_pool",
+        runtime_args! {
+            "token_a" => Key::Hash(token_a.value()),
+            "token_b" => Key::Hash(pool.value()),
+            "fee_bps" => 10_000u32,
+        },
+    )
+    .build();
+    builder.exec(create_pool_request).expect_failure();
+    assert_user_error(&builder, ERROR_INVALID_FEE);
+}
+
+#[test]
+fn should_accrue_and_collect_protocol_fee() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, token_a, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    // Half of the 0.3% fee goes to the fee recipient, the factory admin.
+    let set_protocol_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "set_protocol_fee",
+        runtime_args! {
+            "protocol_fee_share" => 5_000u32,
+        },
+    )
+    .build();
+    builder
+        .exec(set_protocol_fee_request)
+        .expect_success()
+        .commit();
+
+    let amount_in = U256::from(100_000u64);
+    swap_a_to_b(&mut builder, pool, amount_in);
+
+    let protocol_fee = amount_in * 30u64 * 5_000u64 / 10_000u64 / 10_000u64;
+    let protocol_fees_a: U256 = query_stored_value(&builder, pool, "protocol_fees_a");
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(protocol_fees_a, protocol_fee);
+    assert_eq!(reserve_a, amount + amount_in - protocol_fee);
+    // The trader still pays the full fee tier.
+    assert_eq!(
+        amount - reserve_b,
+        expected_amount_out(amount_in, amount, amount, 30)
+    );
+
+    let admin = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let admin_balance = token_balance(&builder, token_a, admin);
+    let collect_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "collect_protocol_fees",
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(collect_request).expect_success().commit();
+
+    let protocol_fees_a: U256 = query_stored_value(&builder, pool, "protocol_fees_a");
+    assert!(protocol_fees_a.is_zero());
+    assert_eq!(
+        token_balance(&builder, token_a, admin),
+        admin_balance + protocol_fee
+    );
+    assert_eq!(
+        token_balance(&builder, token_a, pool_key(&builder, pool)),
+        reserve_a
+    );
+}
+
+#[test]
+fn should_only_let_admin_set_fees() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+    let (user_key, user_account_hash) = get_test_account(1);
+
+    let set_protocol_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        user_account_hash,
+        pool,
+        "set_protocol_fee",
+        runtime_args! {
+            "protocol_fee_share" => 5_000u32,
+        },
+    )
+    .build();
+    builder.exec(set_protocol_fee_request).expect_failure();
+    assert_user_error(&builder, ERROR_NOT_ADMIN);
+
+    let set_fee_recipient_request = ExecuteRequestBuilder::contract_call_by_hash(
+        user_account_hash,
+        pool,
+        "set_fee_recipient",
+        runtime_args! {
+            "fee_recipient" => user_key,
+        },
+    )
+    .build();
+    builder.exec(set_fee_recipient_request).expect_failure();
+    assert_user_error(&builder, ERROR_NOT_ADMIN);
+
+    let set_protocol_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "set_protocol_fee",
+        runtime_args! {
+            "protocol_fee_share" => 10_001u32,
+        },
+    )
+    .build();
+    builder.exec(set_protocol_fee_request).expect_failure();
+    assert_user_error(&builder, ERROR_INVALID_FEE);
+
+    let protocol_fee_share: u32 = query_stored_value(&builder, pool, "protocol_fee_share");
+    assert_eq!(protocol_fee_share, 0);
+}
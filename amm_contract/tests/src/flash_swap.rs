@@ -0,0 +1,189 @@
+use crate::utility::{
+    constants::{
+        ERROR_INVARIANT, FLASH_BORROWER_KEY_NAME, FLASH_BORROWER_PACKAGE_KEY_NAME,
+        FLASH_BORROWER_WASM, LIQUIDITY_AMOUNT,
+    },
+    installer_request_builders::{
+        add_liquidity, assert_user_error, pool_key, query_stored_value, setup, token_balance,
+        TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequest, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, AddressableEntityHash, Key, RuntimeArgs, U256,
+};
+
+const AMOUNT_B_OUT: u64 = 10_000;
+
+/// Smallest repayment of `AMOUNT_B_OUT` in token B that covers the 0.3% fee.
+fn full_repayment() -> U256 {
+    U256::from(AMOUNT_B_OUT) * 10_000u64 / 9_970u64 + 1u64
+}
+
+/// Installs the borrower and funds its package with enough token B to cover the fee, returning
+/// its contract hash and package key.
+fn install_borrower(
+    builder: &mut LmdbWasmTestBuilder,
+    token_b: AddressableEntityHash,
+) -> (AddressableEntityHash, Key) {
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        FLASH_BORROWER_WASM,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let account = builder
+        .get_entity_with_named_keys_by_account_hash(*DEFAULT_ACCOUNT_ADDR)
+        .unwrap();
+    let named_keys = account.named_keys();
+    let borrower = named_keys
+        .get(FLASH_BORROWER_KEY_NAME)
+        .and_then(|key| key.into_entity_hash())
+        .expect("should have borrower contract hash");
+    let borrower_package = match named_keys
+        .get(FLASH_BORROWER_PACKAGE_KEY_NAME)
+        .expect("should have borrower package hash")
+    {
+        Key::Hash(package_hash) | Key::SmartContract(package_hash) => Key::Hash(*package_hash),
+        key => panic!("unexpected package key {key:?}"),
+    };
+
+    let fund_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        token_b,
+        "transfer",
+        runtime_args! {
+            "recipient" => borrower_package,
+            "amount" => full_repayment() - AMOUNT_B_OUT,
+        },
+    )
+    .build();
+    builder.exec(fund_request).expect_success().commit();
+
+    (borrower, borrower_package)
+}
+
+/// Borrows `AMOUNT_B_OUT` of token B into the borrower package, which repays `repay` of token B.
+fn make_flash_swap_request(
+    test_context: TestContext,
+    borrower: AddressableEntityHash,
+    borrower_package: Key,
+    repay: U256,
+) -> ExecuteRequest {
+    let data = (Key::Hash(test_context.token_b.value()), repay)
+        .to_bytes()
+        .unwrap();
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.pool,
+        "flash_swap",
+        runtime_args! {
+            "amount_a_out" => U256::zero(),
+            "amount_b_out" => U256::from(AMOUNT_B_OUT),
+            "callee" => Key::Hash(borrower.value()),
+            "data" => Bytes::from(data),
+            "recipient" => borrower_package,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_settle_repaid_flash_swap() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, token_b, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+    let (borrower, borrower_package) = install_borrower(&mut builder, token_b);
+
+    builder
+        .exec(make_flash_swap_request(
+            test_context,
+            borrower,
+            borrower_package,
+            full_repayment(),
+        ))
+        .expect_success()
+        .commit();
+
+    // The pool keeps the fee; the borrower spent everything it held.
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_a, amount);
+    assert_eq!(reserve_b, amount - AMOUNT_B_OUT + full_repayment());
+    assert_eq!(
+        token_balance(&builder, token_b, pool_key(&builder, pool)),
+        reserve_b
+    );
+    assert!(token_balance(&builder, token_b, borrower_package).is_zero());
+}
+
+#[test]
+fn should_revert_under_repaid_flash_swap() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, token_b, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+    let (borrower, borrower_package) = install_borrower(&mut builder, token_b);
+
+    // Returning exactly the borrowed amount skips the fee.
+    builder
+        .exec(make_flash_swap_request(
+            test_context,
+            borrower,
+            borrower_package,
+            U256::from(AMOUNT_B_OUT),
+        ))
+        .expect_failure();
+    assert_user_error(&builder, ERROR_INVARIANT);
+
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_b, amount);
+    assert_eq!(
+        token_balance(&builder, token_b, borrower_package),
+        full_repayment() - AMOUNT_B_OUT
+    );
+}
+
+#[test]
+fn should_require_flash_swap_recipient() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, token_b, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+    let (borrower, borrower_package) = install_borrower(&mut builder, token_b);
+
+    // Defaulting to `callee` would pay the contract hash, which tokens do not key balances by.
+    let data = (Key::Hash(token_b.value()), full_repayment())
+        .to_bytes()
+        .unwrap();
+    let flash_swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "flash_swap",
+        runtime_args! {
+            "amount_a_out" => U256::zero(),
+            "amount_b_out" => U256::from(AMOUNT_B_OUT),
+            "callee" => Key::Hash(borrower.value()),
+            "data" => Bytes::from(data),
+        },
+    )
+    .build();
+    builder.exec(flash_swap_request).expect_failure();
+
+    assert!(token_balance(&builder, token_b, Key::Hash(borrower.value())).is_zero());
+    assert_eq!(
+        token_balance(&builder, token_b, borrower_package),
+        full_repayment() - AMOUNT_B_OUT
+    );
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_b, amount);
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod concentrated;
+#[cfg(test)]
+mod deadline;
+#[cfg(test)]
+mod donations;
+#[cfg(test)]
+mod events;
+#[cfg(test)]
+mod factory;
+#[cfg(test)]
+mod fees;
+#[cfg(test)]
+mod flash_swap;
+#[cfg(test)]
+mod liquidity;
+#[cfg(test)]
+mod oracle;
+#[cfg(test)]
+mod quotes;
+#[cfg(test)]
+mod reentrancy;
+#[cfg(test)]
+mod router;
+#[cfg(test)]
+mod stableswap;
+#[cfg(test)]
+mod utility;
+#[cfg(test)]
+mod wcspr;
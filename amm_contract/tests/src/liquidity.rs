@@ -0,0 +1,165 @@
+use crate::utility::{
+    constants::{ERROR_INSUFFICIENT_LIQUIDITY, ERROR_PAUSED, LIQUIDITY_AMOUNT},
+    installer_request_builders::{
+        add_liquidity, assert_user_error, call_view, pool_key, query_stored_value, setup,
+        token_balance, TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, AddressableEntityHash, Key, RuntimeArgs, U256};
+
+const MIN_LIQUIDITY: u64 = 1_000;
+
+fn user_shares(builder: &mut LmdbWasmTestBuilder, pool: AddressableEntityHash) -> U256 {
+    call_view(
+        builder,
+        pool,
+        "get_user_shares",
+        runtime_args! {
+            "user" => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    )
+}
+
+fn remove_liquidity_request(pool: AddressableEntityHash, shares: U256) -> ExecuteRequestBuilder {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "remove_liquidity",
+        runtime_args! {
+            "shares" => shares,
+        },
+    )
+}
+
+#[test]
+fn should_mint_shares_for_deposits() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    // The first deposit mints sqrt(a * b), of which MIN_LIQUIDITY stays unowned.
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+    assert_eq!(user_shares(&mut builder, pool), amount - MIN_LIQUIDITY);
+    let total_shares: U256 = call_view(&mut builder, pool, "get_total_shares", RuntimeArgs::new());
+    assert_eq!(total_shares, amount);
+
+    // Later deposits get the smaller proportional share, so the excess B is donated.
+    add_liquidity(&mut builder, pool, amount / 2, amount);
+    assert_eq!(
+        user_shares(&mut builder, pool),
+        amount - MIN_LIQUIDITY + amount / 2
+    );
+    let total_shares: U256 = call_view(&mut builder, pool, "get_total_shares", RuntimeArgs::new());
+    assert_eq!(total_shares, amount + amount / 2);
+}
+
+#[test]
+fn should_remove_liquidity_pro_rata() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        pool,
+        token_a,
+        token_b,
+        ..
+    } = test_context;
+    let provider = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount * 2);
+    let shares = user_shares(&mut builder, pool);
+    let total_shares: U256 = call_view(&mut builder, pool, "get_total_shares", RuntimeArgs::new());
+
+    let balance_a = token_balance(&builder, token_a, provider);
+    let balance_b = token_balance(&builder, token_b, provider);
+    let removed: (U256, U256) = call_view(
+        &mut builder,
+        pool,
+        "remove_liquidity",
+        runtime_args! {
+            "shares" => shares / 2,
+        },
+    );
+    let expected = (
+        shares / 2 * amount / total_shares,
+        shares / 2 * amount * 2 / total_shares,
+    );
+    assert_eq!(removed, expected);
+
+    assert_eq!(user_shares(&mut builder, pool), shares - shares / 2);
+    assert_eq!(
+        token_balance(&builder, token_a, provider),
+        balance_a + expected.0
+    );
+    assert_eq!(
+        token_balance(&builder, token_b, provider),
+        balance_b + expected.1
+    );
+
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_a, amount - expected.0);
+    assert_eq!(reserve_b, amount * 2 - expected.1);
+    let pool_key = pool_key(&builder, pool);
+    assert_eq!(token_balance(&builder, token_a, pool_key), reserve_a);
+    assert_eq!(token_balance(&builder, token_b, pool_key), reserve_b);
+}
+
+#[test]
+fn should_reject_removing_more_shares_than_owned() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+    let shares = user_shares(&mut builder, pool);
+
+    builder
+        .exec(remove_liquidity_request(pool, shares + 1).build())
+        .expect_failure();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_LIQUIDITY);
+}
+
+#[test]
+fn should_remove_liquidity_while_paused() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+    let shares = user_shares(&mut builder, pool);
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "pause",
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    let add_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "add_liquidity",
+        runtime_args! {
+            "amount_a" => amount,
+            "amount_b" => amount,
+        },
+    )
+    .build();
+    builder.exec(add_liquidity_request).expect_failure();
+    assert_user_error(&builder, ERROR_PAUSED);
+
+    builder
+        .exec(remove_liquidity_request(pool, shares).build())
+        .expect_success()
+        .commit();
+    assert!(user_shares(&mut builder, pool).is_zero());
+
+    // Only the unowned MIN_LIQUIDITY shares' part of the reserves stays behind.
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    assert_eq!(reserve_a, U256::from(MIN_LIQUIDITY));
+}
@@ -0,0 +1,136 @@
+use crate::utility::{
+    constants::LIQUIDITY_AMOUNT,
+    installer_request_builders::{add_liquidity, query_stored_value, setup, TestContext},
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::FromBytes, runtime_args, AddressableEntityHash, CLTyped, RuntimeArgs, U256,
+};
+
+const FIRST_SWAP_TIME: u64 = 1_000;
+const SECOND_SWAP_TIME: u64 = 4_000;
+const READ_TIME: u64 = 6_000;
+
+fn spot_price(reserve_base: U256, reserve_quote: U256) -> U256 {
+    reserve_quote * U256::exp10(18) / reserve_base
+}
+
+fn reserves(builder: &LmdbWasmTestBuilder, pool: AddressableEntityHash) -> (U256, U256) {
+    (
+        query_stored_value(builder, pool, "reserve_a"),
+        query_stored_value(builder, pool, "reserve_b"),
+    )
+}
+
+fn swap_at(
+    builder: &mut LmdbWasmTestBuilder,
+    pool: AddressableEntityHash,
+    amount_in: U256,
+    block_time: u64,
+) {
+    let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "swap_a_to_b",
+        runtime_args! {
+            "amount_in" => amount_in,
+            "min_amount_out" => U256::zero(),
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(swap_request).expect_success().commit();
+}
+
+fn view_at<T: CLTyped + FromBytes>(
+    builder: &mut LmdbWasmTestBuilder,
+    pool: AddressableEntityHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+    block_time: u64,
+) -> T {
+    let view_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        entry_point,
+        args,
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(view_request).expect_success().commit();
+    builder
+        .get_last_exec_result()
+        .expect("should have exec result")
+        .ret()
+        .expect("should return a value")
+        .clone()
+        .into_t()
+        .unwrap()
+}
+
+#[test]
+fn should_accumulate_prices_over_block_time() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+    let (initial_a, initial_b) = reserves(&builder, pool);
+
+    // The first swap folds in the 1:1 price that held since the deposit.
+    swap_at(&mut builder, pool, U256::from(100_000u64), FIRST_SWAP_TIME);
+    let price_a_first = spot_price(initial_a, initial_b) * FIRST_SWAP_TIME;
+    let price_b_first = spot_price(initial_b, initial_a) * FIRST_SWAP_TIME;
+    let price_a_cumulative: U256 = query_stored_value(&builder, pool, "price_a_cumulative");
+    let price_b_cumulative: U256 = query_stored_value(&builder, pool, "price_b_cumulative");
+    assert_eq!(price_a_cumulative, price_a_first);
+    assert_eq!(price_b_cumulative, price_b_first);
+    let (first_a, first_b) = reserves(&builder, pool);
+
+    // The second swap folds in the post-swap price, weighted by the time between the swaps.
+    swap_at(&mut builder, pool, U256::from(50_000u64), SECOND_SWAP_TIME);
+    let elapsed = SECOND_SWAP_TIME - FIRST_SWAP_TIME;
+    let price_a_second = price_a_first + spot_price(first_a, first_b) * elapsed;
+    let price_b_second = price_b_first + spot_price(first_b, first_a) * elapsed;
+    let price_a_cumulative: U256 = query_stored_value(&builder, pool, "price_a_cumulative");
+    let price_b_cumulative: U256 = query_stored_value(&builder, pool, "price_b_cumulative");
+    let block_timestamp_last: u64 = query_stored_value(&builder, pool, "block_timestamp_last");
+    assert_eq!(price_a_cumulative, price_a_second);
+    assert_eq!(price_b_cumulative, price_b_second);
+    assert_eq!(block_timestamp_last, SECOND_SWAP_TIME);
+    let (second_a, second_b) = reserves(&builder, pool);
+
+    // Reads extend the accumulators to the current block time without writing them.
+    let (view_a, view_b, timestamp): (U256, U256, u64) = view_at(
+        &mut builder,
+        pool,
+        "get_cumulative_prices",
+        RuntimeArgs::new(),
+        READ_TIME,
+    );
+    let elapsed = READ_TIME - SECOND_SWAP_TIME;
+    assert_eq!(
+        view_a,
+        price_a_second + spot_price(second_a, second_b) * elapsed
+    );
+    assert_eq!(
+        view_b,
+        price_b_second + spot_price(second_b, second_a) * elapsed
+    );
+    assert_eq!(timestamp, READ_TIME);
+    let price_a_cumulative: U256 = query_stored_value(&builder, pool, "price_a_cumulative");
+    assert_eq!(price_a_cumulative, price_a_second);
+
+    // Between the swaps only the post-first-swap price held, so that is the average.
+    let (twap_a, twap_b): (U256, U256) = view_at(
+        &mut builder,
+        pool,
+        "consult",
+        runtime_args! { "window" => SECOND_SWAP_TIME - FIRST_SWAP_TIME },
+        SECOND_SWAP_TIME,
+    );
+    assert_eq!(twap_a, spot_price(first_a, first_b));
+    assert_eq!(twap_b, spot_price(first_b, first_a));
+}
@@ -0,0 +1,178 @@
+use crate::utility::{
+    constants::{ERROR_EXCESSIVE_INPUT, LIQUIDITY_AMOUNT},
+    installer_request_builders::{
+        add_liquidity, assert_user_error, call_view, query_stored_value, setup, swap_a_to_b,
+        TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequest, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, AddressableEntityHash, RuntimeArgs, U256};
+
+const FEE_BPS: u64 = 30;
+
+/// `math::get_amount_out`: rounds down, in the pool's favour.
+fn amount_out_formula(amount_in: U256, reserve_in: U256, reserve_out: U256) -> U256 {
+    let amount_in_with_fee = amount_in * (10_000 - FEE_BPS);
+    amount_in_with_fee * reserve_out / (reserve_in * 10_000u64 + amount_in_with_fee)
+}
+
+/// `math::get_amount_in`: the floor of the exact input plus one, so it rounds up.
+fn amount_in_formula(amount_out: U256, reserve_in: U256, reserve_out: U256) -> U256 {
+    reserve_in * amount_out * 10_000u64 / ((reserve_out - amount_out) * (10_000 - FEE_BPS)) + 1u64
+}
+
+fn setup_with_reserves() -> (LmdbWasmTestBuilder, TestContext, U256, U256) {
+    let (mut builder, test_context) = setup();
+    // Uneven reserves, so neither formula divides exactly.
+    let reserve_a = U256::from(LIQUIDITY_AMOUNT);
+    let reserve_b = U256::from(LIQUIDITY_AMOUNT * 3 + 7);
+    add_liquidity(&mut builder, test_context.pool, reserve_a, reserve_b);
+    (builder, test_context, reserve_a, reserve_b)
+}
+
+fn make_exact_out_request(
+    pool: AddressableEntityHash,
+    entry_point: &str,
+    amount_out: U256,
+    max_amount_in: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        entry_point,
+        runtime_args! {
+            "amount_out" => amount_out,
+            "max_amount_in" => max_amount_in,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_quote_amount_out_matching_swap() {
+    let (mut builder, test_context, reserve_a, reserve_b) = setup_with_reserves();
+    let TestContext { pool, .. } = test_context;
+
+    let amount_in = U256::from(12_345u64);
+    let quoted: U256 = call_view(
+        &mut builder,
+        pool,
+        "get_amount_out",
+        runtime_args! {
+            "amount_in" => amount_in,
+            "a_to_b" => true,
+        },
+    );
+    assert_eq!(quoted, amount_out_formula(amount_in, reserve_a, reserve_b));
+
+    let quoted_b_to_a: U256 = call_view(
+        &mut builder,
+        pool,
+        "get_amount_out",
+        runtime_args! {
+            "amount_in" => amount_in,
+            "a_to_b" => false,
+        },
+    );
+    assert_eq!(
+        quoted_b_to_a,
+        amount_out_formula(amount_in, reserve_b, reserve_a)
+    );
+
+    swap_a_to_b(&mut builder, pool, amount_in);
+    let new_reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_b - new_reserve_b, quoted);
+}
+
+#[test]
+fn should_round_amount_in_up() {
+    let (mut builder, test_context, reserve_a, reserve_b) = setup_with_reserves();
+    let TestContext { pool, .. } = test_context;
+
+    let amount_out = U256::from(10_000u64);
+    let quoted: U256 = call_view(
+        &mut builder,
+        pool,
+        "get_amount_in",
+        runtime_args! {
+            "amount_out" => amount_out,
+            "a_to_b" => true,
+        },
+    );
+    assert_eq!(quoted, amount_in_formula(amount_out, reserve_a, reserve_b));
+
+    // The quote is enough for `amount_out`, and one unit less is not, so it never undercharges.
+    assert!(amount_out_formula(quoted, reserve_a, reserve_b) >= amount_out);
+    assert!(amount_out_formula(quoted - 1u64, reserve_a, reserve_b) < amount_out);
+
+    let swap_request = make_exact_out_request(pool, "swap_a_for_exact_b", amount_out, quoted);
+    builder.exec(swap_request).expect_success().commit();
+
+    let new_reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let new_reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(new_reserve_a - reserve_a, quoted);
+    assert_eq!(reserve_b - new_reserve_b, amount_out);
+}
+
+#[test]
+fn should_swap_b_for_exact_a() {
+    let (mut builder, test_context, reserve_a, reserve_b) = setup_with_reserves();
+    let TestContext { pool, .. } = test_context;
+
+    let amount_out = U256::from(10_000u64);
+    let quoted: U256 = call_view(
+        &mut builder,
+        pool,
+        "get_amount_in",
+        runtime_args! {
+            "amount_out" => amount_out,
+            "a_to_b" => false,
+        },
+    );
+    assert_eq!(quoted, amount_in_formula(amount_out, reserve_b, reserve_a));
+
+    let swap_request = make_exact_out_request(pool, "swap_b_for_exact_a", amount_out, quoted);
+    builder.exec(swap_request).expect_success().commit();
+
+    let new_reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let new_reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_a - new_reserve_a, amount_out);
+    assert_eq!(new_reserve_b - reserve_b, quoted);
+}
+
+#[test]
+fn should_reject_exact_out_swaps_above_max_amount_in() {
+    let (mut builder, test_context, reserve_a, reserve_b) = setup_with_reserves();
+    let TestContext { pool, .. } = test_context;
+
+    let amount_out = U256::from(10_000u64);
+    for (entry_point, reserve_in, reserve_out) in [
+        ("swap_a_for_exact_b", reserve_a, reserve_b),
+        ("swap_b_for_exact_a", reserve_b, reserve_a),
+    ] {
+        // One unit short of the required input is slippage the caller did not accept.
+        let required = amount_in_formula(amount_out, reserve_in, reserve_out);
+        let swap_request = make_exact_out_request(pool, entry_point, amount_out, required - 1u64);
+        builder.exec(swap_request).expect_failure();
+        assert_user_error(&builder, ERROR_EXCESSIVE_INPUT);
+    }
+
+    let new_reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let new_reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(new_reserve_a, reserve_a);
+    assert_eq!(new_reserve_b, reserve_b);
+}
+
+#[test]
+fn should_quote_spot_price() {
+    let (mut builder, test_context, reserve_a, reserve_b) = setup_with_reserves();
+    let TestContext { pool, .. } = test_context;
+
+    let (price_a_in_b, price_b_in_a): (U256, U256) =
+        call_view(&mut builder, pool, "get_spot_price", RuntimeArgs::new());
+    let scale = U256::exp10(18);
+    assert_eq!(price_a_in_b, reserve_b * scale / reserve_a);
+    assert_eq!(price_b_in_a, reserve_a * scale / reserve_b);
+}
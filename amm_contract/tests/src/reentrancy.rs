@@ -0,0 +1,108 @@
+use crate::utility::{
+    constants::{ERROR_LOCKED, LIQUIDITY_AMOUNT},
+    installer_request_builders::{
+        add_liquidity, pool_key, query_stored_value, setup, token_balance, TestContext,
+    },
+};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{runtime_args, ApiError, Key, U256};
+
+#[test]
+fn should_release_lock_after_each_entry_point() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    let locked: bool = query_stored_value(&builder, pool, "locked");
+    assert!(!locked);
+
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_a, amount * 2);
+    assert_eq!(reserve_b, amount * 2);
+}
+
+#[test]
+fn should_move_deposits_and_swap_inputs_into_pool() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        pool,
+        token_a,
+        token_b,
+        ..
+    } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    let pool_key = pool_key(&builder, pool);
+    assert_eq!(token_balance(&builder, token_a, pool_key), amount);
+    assert_eq!(token_balance(&builder, token_b, pool_key), amount);
+
+    let amount_in = U256::from(1_000u64);
+    let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "swap_a_to_b",
+        runtime_args! {
+            "amount_in" => amount_in,
+            "min_amount_out" => U256::zero(),
+        },
+    )
+    .build();
+    builder.exec(swap_request).expect_success().commit();
+
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_a, amount + amount_in);
+    assert!(reserve_b < amount);
+    assert_eq!(token_balance(&builder, token_a, pool_key), reserve_a);
+    assert_eq!(token_balance(&builder, token_b, pool_key), reserve_b);
+}
+
+#[test]
+fn should_reject_token_reentering_swap_a_to_b() {
+    let (mut builder, test_context) = setup();
+    let TestContext { pool, token_a, .. } = test_context;
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    let arm_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        token_a,
+        "arm",
+        runtime_args! {
+            "target" => Key::Hash(pool.value()),
+        },
+    )
+    .build();
+    builder.exec(arm_request).expect_success().commit();
+
+    let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "swap_a_to_b",
+        runtime_args! {
+            "amount_in" => U256::from(1_000u64),
+            "min_amount_out" => U256::zero(),
+        },
+    )
+    .build();
+    builder.exec(swap_request).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_LOCKED),
+        "{error:?}"
+    );
+
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    let reserve_b: U256 = query_stored_value(&builder, pool, "reserve_b");
+    assert_eq!(reserve_a, amount);
+    assert_eq!(reserve_b, amount);
+}
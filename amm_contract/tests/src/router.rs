@@ -0,0 +1,265 @@
+use crate::utility::{
+    constants::{
+        ERROR_ROUTER_EXPIRED, ERROR_ROUTER_INSUFFICIENT_OUTPUT, ERROR_ROUTER_INVALID_PATH,
+        ERROR_ROUTER_POOL_NOT_FOUND, LIQUIDITY_AMOUNT, TOKEN_C_KEY_NAME,
+    },
+    installer_request_builders::{
+        add_liquidity, assert_user_error, call_view, create_pool, install_hostile_token,
+        install_router, query_stored_value, setup, token_balance,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, AddressableEntityHash, Key, U256};
+
+const BLOCK_TIME: u64 = 10_000;
+
+/// Router over a factory with an A/B pool and a B/C pool, each holding `LIQUIDITY_AMOUNT` of
+/// both tokens.
+struct RouterContext {
+    router: AddressableEntityHash,
+    router_package: Key,
+    pool_ab: AddressableEntityHash,
+    pool_bc: AddressableEntityHash,
+    token_a: AddressableEntityHash,
+    token_b: AddressableEntityHash,
+    token_c: AddressableEntityHash,
+}
+
+fn setup_router() -> (LmdbWasmTestBuilder, RouterContext) {
+    let (mut builder, test_context) = setup();
+    let token_c = install_hostile_token(&mut builder, TOKEN_C_KEY_NAME);
+    let pool_bc = create_pool(
+        &mut builder,
+        test_context.factory,
+        test_context.token_b,
+        token_c,
+    );
+
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, test_context.pool, amount, amount);
+    add_liquidity(&mut builder, pool_bc, amount, amount);
+
+    let (router, router_package) = install_router(&mut builder, test_context.factory);
+    let router_context = RouterContext {
+        router,
+        router_package,
+        pool_ab: test_context.pool,
+        pool_bc,
+        token_a: test_context.token_a,
+        token_b: test_context.token_b,
+        token_c,
+    };
+    (builder, router_context)
+}
+
+fn token_path(tokens: &[AddressableEntityHash]) -> Vec<Key> {
+    tokens
+        .iter()
+        .map(|token| Key::Hash(token.value()))
+        .collect()
+}
+
+fn swap_request(
+    router: AddressableEntityHash,
+    path: Vec<Key>,
+    amount_in: U256,
+    min_out: U256,
+    deadline: u64,
+) -> ExecuteRequestBuilder {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        router,
+        "swap_exact_tokens_for_tokens",
+        runtime_args! {
+            "path" => path,
+            "amount_in" => amount_in,
+            "min_out" => min_out,
+            "deadline" => deadline,
+        },
+    )
+    .with_block_time(BLOCK_TIME)
+}
+
+/// Output of one constant-product hop at the default 0.3% fee.
+fn hop_output(amount_in: U256, reserve_in: U256, reserve_out: U256) -> U256 {
+    let amount_in_with_fee = amount_in * 9_970u64;
+    amount_in_with_fee * reserve_out / (reserve_in * 10_000u64 + amount_in_with_fee)
+}
+
+fn two_hop_output(amount_in: U256) -> U256 {
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    hop_output(hop_output(amount_in, amount, amount), amount, amount)
+}
+
+fn assert_reserves_untouched(builder: &LmdbWasmTestBuilder, router_context: &RouterContext) {
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    for pool in [router_context.pool_ab, router_context.pool_bc] {
+        let reserve_a: U256 = query_stored_value(builder, pool, "reserve_a");
+        let reserve_b: U256 = query_stored_value(builder, pool, "reserve_b");
+        assert_eq!((reserve_a, reserve_b), (amount, amount));
+    }
+}
+
+#[test]
+fn should_swap_along_two_hop_path() {
+    let (mut builder, router_context) = setup_router();
+    let RouterContext {
+        router,
+        router_package,
+        token_a,
+        token_b,
+        token_c,
+        ..
+    } = router_context;
+    let trader = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let amount_in = U256::from(10_000u64);
+    let balance_a = token_balance(&builder, token_a, trader);
+    let balance_c = token_balance(&builder, token_c, trader);
+    builder
+        .exec(
+            swap_request(
+                router,
+                token_path(&[token_a, token_b, token_c]),
+                amount_in,
+                U256::zero(),
+                u64::MAX,
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+
+    let amount_out = two_hop_output(amount_in);
+    assert_eq!(
+        token_balance(&builder, token_a, trader),
+        balance_a - amount_in
+    );
+    assert_eq!(
+        token_balance(&builder, token_c, trader),
+        balance_c + amount_out
+    );
+
+    // Every hop spends exactly what the router received, so nothing is left behind.
+    for token in [token_a, token_b, token_c] {
+        assert!(token_balance(&builder, token, router_package).is_zero());
+    }
+}
+
+#[test]
+fn should_return_two_hop_output() {
+    let (mut builder, router_context) = setup_router();
+    let RouterContext {
+        router,
+        token_a,
+        token_b,
+        token_c,
+        ..
+    } = router_context;
+
+    let amount_in = U256::from(10_000u64);
+    let amount_out: U256 = call_view(
+        &mut builder,
+        router,
+        "swap_exact_tokens_for_tokens",
+        runtime_args! {
+            "path" => token_path(&[token_a, token_b, token_c]),
+            "amount_in" => amount_in,
+            "min_out" => U256::zero(),
+            "deadline" => u64::MAX,
+        },
+    );
+    assert_eq!(amount_out, two_hop_output(amount_in));
+}
+
+#[test]
+fn should_revert_path_below_min_out() {
+    let (mut builder, router_context) = setup_router();
+    let RouterContext {
+        router,
+        token_a,
+        token_b,
+        token_c,
+        ..
+    } = router_context;
+
+    let amount_in = U256::from(10_000u64);
+    builder
+        .exec(
+            swap_request(
+                router,
+                token_path(&[token_a, token_b, token_c]),
+                amount_in,
+                two_hop_output(amount_in) + 1u64,
+                u64::MAX,
+            )
+            .build(),
+        )
+        .expect_failure();
+    assert_user_error(&builder, ERROR_ROUTER_INSUFFICIENT_OUTPUT);
+    assert_reserves_untouched(&builder, &router_context);
+}
+
+#[test]
+fn should_revert_path_after_deadline() {
+    let (mut builder, router_context) = setup_router();
+    let RouterContext {
+        router,
+        token_a,
+        token_b,
+        token_c,
+        ..
+    } = router_context;
+
+    builder
+        .exec(
+            swap_request(
+                router,
+                token_path(&[token_a, token_b, token_c]),
+                U256::from(10_000u64),
+                U256::zero(),
+                BLOCK_TIME - 1,
+            )
+            .build(),
+        )
+        .expect_failure();
+    assert_user_error(&builder, ERROR_ROUTER_EXPIRED);
+    assert_reserves_untouched(&builder, &router_context);
+}
+
+#[test]
+fn should_reject_invalid_paths() {
+    let (mut builder, router_context) = setup_router();
+    let RouterContext {
+        router,
+        token_a,
+        token_c,
+        ..
+    } = router_context;
+    let amount_in = U256::from(10_000u64);
+
+    for path in [Vec::new(), token_path(&[token_a])] {
+        builder
+            .exec(swap_request(router, path, amount_in, U256::zero(), u64::MAX).build())
+            .expect_failure();
+        assert_user_error(&builder, ERROR_ROUTER_INVALID_PATH);
+    }
+
+    // A and C only meet through B; there is no direct pool.
+    builder
+        .exec(
+            swap_request(
+                router,
+                token_path(&[token_a, token_c]),
+                amount_in,
+                U256::zero(),
+                u64::MAX,
+            )
+            .build(),
+        )
+        .expect_failure();
+    assert_user_error(&builder, ERROR_ROUTER_POOL_NOT_FOUND);
+    assert_reserves_untouched(&builder, &router_context);
+}
@@ -0,0 +1,59 @@
+use crate::utility::{
+    constants::LIQUIDITY_AMOUNT,
+    installer_request_builders::{
+        add_liquidity, pool_key, query_stored_value, setup, setup_stable, token_balance,
+        TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, U256};
+
+/// Adds balanced liquidity, swaps `amount_in` of token A and returns the token B paid out, after
+/// checking that it actually left the pool.
+fn swap_output(
+    builder: &mut LmdbWasmTestBuilder,
+    test_context: TestContext,
+    amount_in: U256,
+) -> U256 {
+    let TestContext { pool, token_b, .. } = test_context;
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(builder, pool, amount, amount);
+
+    let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "swap_a_to_b",
+        runtime_args! {
+            "amount_in" => amount_in,
+            "min_amount_out" => U256::zero(),
+        },
+    )
+    .build();
+    builder.exec(swap_request).expect_success().commit();
+
+    let reserve_b: U256 = query_stored_value(builder, pool, "reserve_b");
+    let pool_key = pool_key(builder, pool);
+    assert_eq!(token_balance(builder, token_b, pool_key), reserve_b);
+    amount - reserve_b
+}
+
+#[test]
+fn should_swap_pegged_pair_with_less_slippage() {
+    let amount_in = U256::from(LIQUIDITY_AMOUNT / 10);
+
+    let (mut builder, test_context) = setup();
+    let constant_product_out = swap_output(&mut builder, test_context, amount_in);
+
+    let (mut builder, test_context) = setup_stable(100);
+    let stable_out = swap_output(&mut builder, test_context, amount_in);
+    let TestContext { pool, .. } = test_context;
+
+    let amp: u64 = query_stored_value(&builder, pool, "amp");
+    assert_eq!(amp, 100);
+    assert!(stable_out > constant_product_out);
+    // Within the fee and a few units of rounding of a 1:1 trade.
+    assert!(stable_out < amount_in);
+    assert!(stable_out > amount_in * 99 / 100);
+}
@@ -0,0 +1,42 @@
+pub const AMM_CONTRACT_WASM: &str = "simple_amm.wasm";
+pub const HOSTILE_TOKEN_WASM: &str = "hostile_token.wasm";
+pub const FLASH_BORROWER_WASM: &str = "flash_borrower.wasm";
+pub const ROUTER_WASM: &str = "simple_amm_router.wasm";
+pub const WCSPR_WASM: &str = "wcspr.wasm";
+pub const CSPR_CALLER_WASM: &str = "cspr_caller.wasm";
+
+pub const FACTORY_KEY_NAME: &str = "simple_amm_factory";
+pub const TOKEN_A_KEY_NAME: &str = "hostile_token_a";
+pub const TOKEN_B_KEY_NAME: &str = "hostile_token_b";
+pub const TOKEN_C_KEY_NAME: &str = "hostile_token_c";
+pub const FLASH_BORROWER_KEY_NAME: &str = "flash_borrower";
+pub const FLASH_BORROWER_PACKAGE_KEY_NAME: &str = "flash_borrower_package";
+pub const ROUTER_KEY_NAME: &str = "simple_amm_router";
+pub const ROUTER_PACKAGE_KEY_NAME: &str = "simple_amm_router_package";
+pub const WCSPR_KEY_NAME: &str = "wcspr_contract_hash";
+
+pub const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+pub const LIQUIDITY_AMOUNT: u64 = 1_000_000;
+pub const TICK_SPACING: u32 = 60;
+
+pub const ERROR_EXPIRED: u16 = 2;
+pub const ERROR_EXCESSIVE_INPUT: u16 = 3;
+pub const ERROR_INSUFFICIENT_LIQUIDITY: u16 = 4;
+pub const ERROR_INVALID_FEE: u16 = 5;
+pub const ERROR_NOT_ADMIN: u16 = 6;
+pub const ERROR_POOL_EXISTS: u16 = 8;
+pub const ERROR_IDENTICAL_TOKENS: u16 = 9;
+pub const ERROR_LOCKED: u16 = 13;
+pub const ERROR_INVARIANT: u16 = 15;
+pub const ERROR_PAUSED: u16 = 16;
+pub const ERROR_INVALID_TICK: u16 = 17;
+pub const ERROR_INVALID_EVENTS_MODE: u16 = 21;
+pub const ERROR_WCSPR_NOT_SET: u16 = 22;
+
+pub const ERROR_ROUTER_INVALID_PATH: u16 = 1;
+pub const ERROR_ROUTER_POOL_NOT_FOUND: u16 = 2;
+pub const ERROR_ROUTER_INSUFFICIENT_OUTPUT: u16 = 3;
+pub const ERROR_ROUTER_EXPIRED: u16 = 4;
+
+pub const ERROR_WCSPR_ZERO_AMOUNT: u16 = 1;
+pub const ERROR_CEP18_INSUFFICIENT_BALANCE: u16 = 60001;
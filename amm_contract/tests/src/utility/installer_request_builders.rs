@@ -0,0 +1,353 @@
+use super::constants::{
+    AMM_CONTRACT_WASM, FACTORY_KEY_NAME, HOSTILE_TOKEN_WASM, ROUTER_KEY_NAME,
+    ROUTER_PACKAGE_KEY_NAME, ROUTER_WASM, TICK_SPACING, TOKEN_A_KEY_NAME, TOKEN_B_KEY_NAME,
+    TOKEN_TOTAL_SUPPLY,
+};
+use casper_engine_test_support::{
+    utils::create_run_genesis_request, ExecuteRequestBuilder, LmdbWasmTestBuilder,
+    DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{FromBytes, ToBytes},
+    crypto::blake2b,
+    runtime_args, AddressableEntityHash, ApiError, CLTyped, EntityAddr, Key, RuntimeArgs, U256,
+};
+
+#[derive(Copy, Clone)]
+pub(crate) struct TestContext {
+    pub(crate) factory: AddressableEntityHash,
+    pub(crate) pool: AddressableEntityHash,
+    pub(crate) token_a: AddressableEntityHash,
+    pub(crate) token_b: AddressableEntityHash,
+}
+
+/// Installs a hostile token under the account named key `name` and returns its contract hash.
+pub(crate) fn install_hostile_token(
+    builder: &mut LmdbWasmTestBuilder,
+    name: &str,
+) -> AddressableEntityHash {
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        HOSTILE_TOKEN_WASM,
+        runtime_args! {
+            "name" => name,
+            "total_supply" => U256::from(TOKEN_TOTAL_SUPPLY),
+        },
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    builder
+        .get_entity_with_named_keys_by_account_hash(*DEFAULT_ACCOUNT_ADDR)
+        .unwrap()
+        .named_keys()
+        .get(name)
+        .and_then(|key| key.into_entity_hash())
+        .expect("should have token contract hash")
+}
+
+/// Installs two hostile tokens and the AMM factory, and creates a pool for the pair through the
+/// factory.
+pub(crate) fn setup() -> (LmdbWasmTestBuilder, TestContext) {
+    setup_with_pool("create_pool", RuntimeArgs::new())
+}
+
+/// Like [`setup`], but creates a concentrated-liquidity pool starting at a price of 1.
+pub(crate) fn setup_concentrated() -> (LmdbWasmTestBuilder, TestContext) {
+    setup_with_pool(
+        "create_concentrated_pool",
+        runtime_args! {
+            "tick_spacing" => TICK_SPACING,
+            "sqrt_price_x96" => U256::one() << 96,
+        },
+    )
+}
+
+/// Like [`setup`], but creates a StableSwap pool with amplification coefficient `amp`.
+pub(crate) fn setup_stable(amp: u64) -> (LmdbWasmTestBuilder, TestContext) {
+    setup_with_pool("create_pool", runtime_args! { "amp" => amp })
+}
+
+/// Like [`setup`], but creates the pool with a `fee_bps` fee tier.
+pub(crate) fn setup_with_fee(fee_bps: u32) -> (LmdbWasmTestBuilder, TestContext) {
+    setup_with_pool("create_pool", runtime_args! { "fee_bps" => fee_bps })
+}
+
+fn setup_with_pool(
+    create_entry_point: &str,
+    mut create_args: RuntimeArgs,
+) -> (LmdbWasmTestBuilder, TestContext) {
+    let mut builder = LmdbWasmTestBuilder::default();
+    builder
+        .run_genesis(create_run_genesis_request(DEFAULT_ACCOUNTS.to_vec()))
+        .commit();
+
+    let token_a = install_hostile_token(&mut builder, TOKEN_A_KEY_NAME);
+    let token_b = install_hostile_token(&mut builder, TOKEN_B_KEY_NAME);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        AMM_CONTRACT_WASM,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let factory = builder
+        .get_entity_with_named_keys_by_account_hash(*DEFAULT_ACCOUNT_ADDR)
+        .unwrap()
+        .named_keys()
+        .get(FACTORY_KEY_NAME)
+        .and_then(|key| key.into_entity_hash())
+        .expect("should have contract hash");
+
+    create_args
+        .insert("token_a", Key::Hash(token_a.value()))
+        .unwrap();
+    create_args
+        .insert("token_b", Key::Hash(token_b.value()))
+        .unwrap();
+    let create_pool_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        factory,
+        create_entry_point,
+        create_args,
+    )
+    .build();
+    builder.exec(create_pool_request).expect_success().commit();
+
+    let pool: Key = get_dictionary_value(&builder, factory, "all_pools", "0");
+    let pool = AddressableEntityHash::new(pool.into_hash_addr().expect("pool should be a hash"));
+
+    let test_context = TestContext {
+        factory,
+        pool,
+        token_a,
+        token_b,
+    };
+
+    (builder, test_context)
+}
+
+/// Creates a constant-product pool for the pair through the factory and returns its contract
+/// hash.
+pub(crate) fn create_pool(
+    builder: &mut LmdbWasmTestBuilder,
+    factory: AddressableEntityHash,
+    token_x: AddressableEntityHash,
+    token_y: AddressableEntityHash,
+) -> AddressableEntityHash {
+    let create_pool_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        factory,
+        "create_pool",
+        runtime_args! {
+            "token_a" => Key::Hash(token_x.value()),
+            "token_b" => Key::Hash(token_y.value()),
+        },
+    )
+    .build();
+    builder.exec(create_pool_request).expect_success().commit();
+
+    let pool_count: u64 = query_stored_value(builder, factory, "pool_count");
+    let pool: Key =
+        get_dictionary_value(builder, factory, "all_pools", &(pool_count - 1).to_string());
+    AddressableEntityHash::new(pool.into_hash_addr().expect("pool should be a hash"))
+}
+
+/// Installs the router over `factory` and returns its contract hash and its package key as tokens
+/// see it.
+pub(crate) fn install_router(
+    builder: &mut LmdbWasmTestBuilder,
+    factory: AddressableEntityHash,
+) -> (AddressableEntityHash, Key) {
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        ROUTER_WASM,
+        runtime_args! {
+            "factory" => Key::Hash(factory.value()),
+        },
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let account = builder
+        .get_entity_with_named_keys_by_account_hash(*DEFAULT_ACCOUNT_ADDR)
+        .unwrap();
+    let named_keys = account.named_keys();
+    let router = named_keys
+        .get(ROUTER_KEY_NAME)
+        .and_then(|key| key.into_entity_hash())
+        .expect("should have router contract hash");
+    let router_package = match named_keys
+        .get(ROUTER_PACKAGE_KEY_NAME)
+        .expect("should have router package hash")
+    {
+        Key::Hash(package_hash) | Key::SmartContract(package_hash) => Key::Hash(*package_hash),
+        key => panic!("unexpected package key {key:?}"),
+    };
+    (router, router_package)
+}
+
+/// Key and account hash of the genesis account at `index` of `DEFAULT_ACCOUNTS`.
+pub(crate) fn get_test_account(index: usize) -> (Key, AccountHash) {
+    let account_hash = DEFAULT_ACCOUNTS[index].public_key().to_account_hash();
+    (Key::Account(account_hash), account_hash)
+}
+
+pub(crate) fn get_dictionary_value<T: CLTyped + FromBytes>(
+    builder: &LmdbWasmTestBuilder,
+    contract_hash: AddressableEntityHash,
+    dictionary_name: &str,
+    dictionary_key: &str,
+) -> T {
+    let named_keys = builder.get_named_keys(EntityAddr::SmartContract(contract_hash.value()));
+    let seed_uref = *named_keys
+        .get(dictionary_name)
+        .expect("must have key")
+        .as_uref()
+        .expect("must convert to seed uref");
+
+    builder
+        .query_dictionary_item(None, seed_uref, dictionary_key)
+        .expect("should have dictionary value")
+        .as_cl_value()
+        .expect("T should be CLValue")
+        .to_owned()
+        .into_t()
+        .unwrap()
+}
+
+pub(crate) fn query_stored_value<T: CLTyped + FromBytes>(
+    builder: &LmdbWasmTestBuilder,
+    contract_hash: AddressableEntityHash,
+    name: &str,
+) -> T {
+    let stored = builder.query(None, Key::Hash(contract_hash.value()), &[name.to_string()]);
+    let cl_value = stored
+        .expect("must have stored value")
+        .as_cl_value()
+        .cloned()
+        .expect("must have cl value");
+
+    cl_value.into_t::<T>().expect("must get value")
+}
+
+/// Asserts that the last request reverted with `User(expected)`.
+pub(crate) fn assert_user_error(builder: &LmdbWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{error:?}"
+    );
+}
+
+pub(crate) fn add_liquidity(
+    builder: &mut LmdbWasmTestBuilder,
+    pool: AddressableEntityHash,
+    amount_a: U256,
+    amount_b: U256,
+) {
+    let add_liquidity_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "add_liquidity",
+        runtime_args! {
+            "amount_a" => amount_a,
+            "amount_b" => amount_b,
+        },
+    )
+    .build();
+    builder
+        .exec(add_liquidity_request)
+        .expect_success()
+        .commit();
+}
+
+/// Key the tokens record the pool's balances under: its contract package.
+pub(crate) fn pool_key(builder: &LmdbWasmTestBuilder, pool: AddressableEntityHash) -> Key {
+    let named_keys = builder.get_named_keys(EntityAddr::SmartContract(pool.value()));
+    let package_key = named_keys
+        .get("package_hash")
+        .expect("must have package hash");
+    match package_key {
+        Key::Hash(package_hash) | Key::SmartContract(package_hash) => Key::Hash(*package_hash),
+        key => panic!("unexpected package key {key:?}"),
+    }
+}
+
+/// Balance of `address` in a test token, zero if it never held any.
+pub(crate) fn token_balance(
+    builder: &LmdbWasmTestBuilder,
+    token: AddressableEntityHash,
+    address: Key,
+) -> U256 {
+    let named_keys = builder.get_named_keys(EntityAddr::SmartContract(token.value()));
+    let seed_uref = *named_keys
+        .get("balances")
+        .expect("must have key")
+        .as_uref()
+        .expect("must convert to seed uref");
+    let dictionary_key: String = blake2b(address.to_bytes().unwrap())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+
+    builder
+        .query_dictionary_item(None, seed_uref, &dictionary_key)
+        .map(|stored_value| {
+            stored_value
+                .as_cl_value()
+                .expect("should be CLValue")
+                .to_owned()
+                .into_t()
+                .unwrap()
+        })
+        .unwrap_or_default()
+}
+
+pub(crate) fn swap_a_to_b(
+    builder: &mut LmdbWasmTestBuilder,
+    pool: AddressableEntityHash,
+    amount_in: U256,
+) {
+    let swap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        pool,
+        "swap_a_to_b",
+        runtime_args! {
+            "amount_in" => amount_in,
+            "min_amount_out" => U256::zero(),
+        },
+    )
+    .build();
+    builder.exec(swap_request).expect_success().commit();
+}
+
+/// Calls `entry_point` on `contract` as the default account and returns the value it passed to
+/// `runtime::ret`.
+pub(crate) fn call_view<T: CLTyped + FromBytes>(
+    builder: &mut LmdbWasmTestBuilder,
+    contract: AddressableEntityHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+) -> T {
+    let view_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract,
+        entry_point,
+        args,
+    )
+    .build();
+    builder.exec(view_request).expect_success().commit();
+    builder
+        .get_last_exec_result()
+        .expect("should have exec result")
+        .ret()
+        .expect("should return a value")
+        .clone()
+        .into_t()
+        .unwrap()
+}
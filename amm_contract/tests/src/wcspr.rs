@@ -0,0 +1,383 @@
+use crate::utility::{
+    constants::{
+        CSPR_CALLER_WASM, ERROR_CEP18_INSUFFICIENT_BALANCE, ERROR_WCSPR_NOT_SET,
+        ERROR_WCSPR_ZERO_AMOUNT, LIQUIDITY_AMOUNT, WCSPR_KEY_NAME, WCSPR_WASM,
+    },
+    installer_request_builders::{
+        add_liquidity, assert_user_error, call_view, get_dictionary_value, pool_key,
+        query_stored_value, setup, token_balance,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    contract_messages::MessagePayload,
+    runtime_args, AddressableEntityHash, EntityAddr, Key, RuntimeArgs, URef, U256, U512,
+};
+
+const PURSE_NAME: &str = "cspr_caller_purse";
+const SWAP_AMOUNT: u64 = 10_000;
+
+/// WCSPR over native messages and a WCSPR/A pool, created with `wcspr`, holding
+/// `LIQUIDITY_AMOUNT` of both tokens.
+struct WcsprContext {
+    wcspr: AddressableEntityHash,
+    token_a: AddressableEntityHash,
+    pool: AddressableEntityHash,
+    pool_package: Key,
+}
+
+/// Runs the `cspr-caller` session: funds a fresh purse with `motes` and calls `entry_point` on
+/// `target` with `call_args` and that purse.
+fn cspr_caller_request(
+    target: AddressableEntityHash,
+    entry_point: &str,
+    motes: u64,
+    call_args: RuntimeArgs,
+) -> ExecuteRequestBuilder {
+    ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CSPR_CALLER_WASM,
+        runtime_args! {
+            "target" => Key::Hash(target.value()),
+            "entry_point" => entry_point,
+            "motes" => U512::from(motes),
+            "purse_name" => PURSE_NAME,
+            "call_args" => Bytes::from(call_args.to_bytes().unwrap()),
+        },
+    )
+}
+
+fn wrap(builder: &mut LmdbWasmTestBuilder, wcspr: AddressableEntityHash, amount: u64) {
+    builder
+        .exec(
+            cspr_caller_request(
+                wcspr,
+                "deposit",
+                amount,
+                runtime_args! { "amount" => U256::from(amount) },
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+}
+
+fn wcspr_balance(
+    builder: &mut LmdbWasmTestBuilder,
+    wcspr: AddressableEntityHash,
+    address: Key,
+) -> U256 {
+    call_view(
+        builder,
+        wcspr,
+        "balance_of",
+        runtime_args! { "address" => address },
+    )
+}
+
+/// Balance of the purse the last `cspr-caller` session created.
+fn caller_purse_balance(builder: &LmdbWasmTestBuilder) -> U512 {
+    let purse: URef = *builder
+        .get_entity_with_named_keys_by_account_hash(*DEFAULT_ACCOUNT_ADDR)
+        .unwrap()
+        .named_keys()
+        .get(PURSE_NAME)
+        .and_then(|key| key.as_uref())
+        .expect("should have caller purse");
+    builder.get_purse_balance(purse)
+}
+
+fn wcspr_purse_balance(builder: &LmdbWasmTestBuilder, wcspr: AddressableEntityHash) -> U512 {
+    let purse: URef = *builder
+        .get_named_keys(EntityAddr::SmartContract(wcspr.value()))
+        .get("purse")
+        .and_then(|key| key.as_uref())
+        .expect("should have wcspr purse");
+    builder.get_purse_balance(purse)
+}
+
+fn setup_wcspr() -> (LmdbWasmTestBuilder, WcsprContext) {
+    let (mut builder, test_context) = setup();
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        WCSPR_WASM,
+        runtime_args! { "events_mode" => 2u8 },
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+    let wcspr = builder
+        .get_entity_with_named_keys_by_account_hash(*DEFAULT_ACCOUNT_ADDR)
+        .unwrap()
+        .named_keys()
+        .get(WCSPR_KEY_NAME)
+        .and_then(|key| key.into_entity_hash())
+        .expect("should have wcspr contract hash");
+
+    let create_pool_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.factory,
+        "create_pool",
+        runtime_args! {
+            "token_a" => Key::Hash(wcspr.value()),
+            "token_b" => Key::Hash(test_context.token_a.value()),
+            "wcspr" => Key::Hash(wcspr.value()),
+        },
+    )
+    .build();
+    builder.exec(create_pool_request).expect_success().commit();
+    let pool: Key = get_dictionary_value(&builder, test_context.factory, "all_pools", "1");
+    let pool = AddressableEntityHash::new(pool.into_hash_addr().expect("pool should be a hash"));
+    wrap(&mut builder, wcspr, LIQUIDITY_AMOUNT);
+
+    // WCSPR is a real CEP-18 token, so the pool needs an allowance to pull the deposit.
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        wcspr,
+        "approve",
+        runtime_args! {
+            "spender" => pool_key(&builder, pool),
+            "amount" => U256::from(LIQUIDITY_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+    let amount = U256::from(LIQUIDITY_AMOUNT);
+    add_liquidity(&mut builder, pool, amount, amount);
+
+    let wcspr_context = WcsprContext {
+        wcspr,
+        token_a: test_context.token_a,
+        pool,
+        pool_package: pool_key(&builder, pool),
+    };
+    (builder, wcspr_context)
+}
+
+/// Output of a swap through the WCSPR/A pool at the default 0.3% fee.
+fn swap_output(amount_in: U256) -> U256 {
+    let reserve = U256::from(LIQUIDITY_AMOUNT);
+    let amount_in_with_fee = amount_in * 9_970u64;
+    amount_in_with_fee * reserve / (reserve * 10_000u64 + amount_in_with_fee)
+}
+
+#[test]
+fn should_wrap_and_unwrap_cspr() {
+    let (mut builder, wcspr_context) = setup_wcspr();
+    let WcsprContext { wcspr, .. } = wcspr_context;
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    // All the wrapped CSPR so far sits in the pool.
+    assert_eq!(
+        wcspr_purse_balance(&builder, wcspr),
+        U512::from(LIQUIDITY_AMOUNT)
+    );
+
+    wrap(&mut builder, wcspr, 1_000);
+    assert_eq!(
+        wcspr_balance(&mut builder, wcspr, owner),
+        U256::from(1_000u64)
+    );
+    assert_eq!(caller_purse_balance(&builder), U512::zero());
+
+    builder
+        .exec(
+            cspr_caller_request(
+                wcspr,
+                "withdraw",
+                0,
+                runtime_args! { "amount" => U256::from(400u64) },
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+
+    assert_eq!(caller_purse_balance(&builder), U512::from(400u64));
+    assert_eq!(
+        wcspr_balance(&mut builder, wcspr, owner),
+        U256::from(600u64)
+    );
+    let total_supply: U256 = query_stored_value(&builder, wcspr, "total_supply");
+    assert_eq!(total_supply, U256::from(LIQUIDITY_AMOUNT + 600));
+    assert_eq!(
+        wcspr_purse_balance(&builder, wcspr),
+        U512::from(LIQUIDITY_AMOUNT + 600)
+    );
+}
+
+#[test]
+fn should_reject_over_withdraw_and_zero_amounts() {
+    let (mut builder, wcspr_context) = setup_wcspr();
+    let WcsprContext { wcspr, .. } = wcspr_context;
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    wrap(&mut builder, wcspr, 1_000);
+
+    builder
+        .exec(
+            cspr_caller_request(
+                wcspr,
+                "withdraw",
+                0,
+                runtime_args! { "amount" => U256::from(1_001u64) },
+            )
+            .build(),
+        )
+        .expect_failure();
+    assert_user_error(&builder, ERROR_CEP18_INSUFFICIENT_BALANCE);
+    assert_eq!(
+        wcspr_balance(&mut builder, wcspr, owner),
+        U256::from(1_000u64)
+    );
+
+    builder
+        .exec(
+            cspr_caller_request(
+                wcspr,
+                "deposit",
+                0,
+                runtime_args! { "amount" => U256::zero() },
+            )
+            .build(),
+        )
+        .expect_failure();
+    assert_user_error(&builder, ERROR_WCSPR_ZERO_AMOUNT);
+}
+
+#[test]
+fn should_swap_cspr_for_tokens_through_pool() {
+    let (mut builder, wcspr_context) = setup_wcspr();
+    let WcsprContext {
+        wcspr,
+        token_a,
+        pool,
+        pool_package,
+    } = wcspr_context;
+    let trader = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let amount_in = U256::from(SWAP_AMOUNT);
+    let balance_a = token_balance(&builder, token_a, trader);
+    builder
+        .exec(
+            cspr_caller_request(
+                pool,
+                "swap_cspr_for_tokens",
+                SWAP_AMOUNT,
+                runtime_args! {
+                    "amount_in" => amount_in,
+                    "min_amount_out" => U256::zero(),
+                    "deadline" => u64::MAX,
+                },
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+
+    // The pool wraps on its own behalf: WCSPR mints to the pool package, not the trader.
+    let mint_payload = format!(
+        "{{\"recipient\":\"{}\",\"amount\":\"{}\"}}",
+        pool_package.to_formatted_string(),
+        amount_in
+    );
+    let exec_result = builder.get_last_exec_result().unwrap();
+    assert!(
+        exec_result
+            .messages()
+            .iter()
+            .any(|message| matches!(message.payload(), MessagePayload::String(payload) if *payload == mint_payload)),
+        "no mint to the pool package"
+    );
+
+    assert_eq!(caller_purse_balance(&builder), U512::zero());
+    assert_eq!(
+        token_balance(&builder, token_a, trader),
+        balance_a + swap_output(amount_in)
+    );
+    assert!(wcspr_balance(&mut builder, wcspr, trader).is_zero());
+    assert_eq!(
+        wcspr_balance(&mut builder, wcspr, pool_package),
+        U256::from(LIQUIDITY_AMOUNT) + amount_in
+    );
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    assert_eq!(reserve_a, U256::from(LIQUIDITY_AMOUNT) + amount_in);
+}
+
+#[test]
+fn should_swap_tokens_for_cspr_through_pool() {
+    let (mut builder, wcspr_context) = setup_wcspr();
+    let WcsprContext {
+        wcspr,
+        token_a,
+        pool,
+        pool_package,
+    } = wcspr_context;
+    let trader = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let amount_in = U256::from(SWAP_AMOUNT);
+    let balance_a = token_balance(&builder, token_a, trader);
+    builder
+        .exec(
+            cspr_caller_request(
+                pool,
+                "swap_tokens_for_cspr",
+                0,
+                runtime_args! {
+                    "amount_in" => amount_in,
+                    "min_amount_out" => U256::zero(),
+                },
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+
+    // The output is unwrapped straight into the purse and burned from the pool's reserve.
+    let amount_out = swap_output(amount_in);
+    assert_eq!(
+        caller_purse_balance(&builder),
+        U512::from(amount_out.as_u64())
+    );
+    assert_eq!(
+        token_balance(&builder, token_a, trader),
+        balance_a - amount_in
+    );
+    assert!(wcspr_balance(&mut builder, wcspr, trader).is_zero());
+    assert_eq!(
+        wcspr_balance(&mut builder, wcspr, pool_package),
+        U256::from(LIQUIDITY_AMOUNT) - amount_out
+    );
+    let reserve_a: U256 = query_stored_value(&builder, pool, "reserve_a");
+    assert_eq!(reserve_a, U256::from(LIQUIDITY_AMOUNT) - amount_out);
+    assert_eq!(
+        wcspr_purse_balance(&builder, wcspr),
+        U512::from(LIQUIDITY_AMOUNT) - U512::from(amount_out.as_u64())
+    );
+}
+
+#[test]
+fn should_reject_cspr_swaps_on_pools_created_without_wcspr() {
+    let (mut builder, test_context) = setup();
+
+    for entry_point in ["swap_cspr_for_tokens", "swap_tokens_for_cspr"] {
+        builder
+            .exec(
+                cspr_caller_request(
+                    test_context.pool,
+                    entry_point,
+                    SWAP_AMOUNT,
+                    runtime_args! {
+                        "amount_in" => U256::from(SWAP_AMOUNT),
+                        "min_amount_out" => U256::zero(),
+                    },
+                )
+                .build(),
+            )
+            .expect_failure();
+        assert_user_error(&builder, ERROR_WCSPR_NOT_SET);
+    }
+}
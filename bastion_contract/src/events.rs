@@ -0,0 +1,29 @@
+//! Dark pool events, emitted as JSON native messages (`{"Paused":{...}}`) on the `events` message
+//! topic, in the same shape as the AMM pool events.
+use alloc::string::String;
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ApiError, Key};
+use serde::Serialize;
+
+use crate::EVENTS_TOPIC;
+
+#[derive(Serialize)]
+pub(crate) enum BastionEvent {
+    Paused(Paused),
+    Unpaused(Unpaused),
+}
+
+#[derive(Serialize)]
+pub(crate) struct Paused {
+    pub admin: Key,
+}
+
+#[derive(Serialize)]
+pub(crate) struct Unpaused {
+    pub admin: Key,
+}
+
+pub(crate) fn emit_event(event: BastionEvent) {
+    let payload: String = serde_json::to_string(&event).unwrap_or_revert_with(ApiError::Formatting);
+    runtime::emit_message(EVENTS_TOPIC, &payload.into()).unwrap_or_revert();
+}
@@ -4,23 +4,35 @@
 
 extern crate alloc;
 
-use alloc::{string::String, vec::Vec};
+mod events;
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use casper_contract::contract_api::{runtime, storage};
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    contract_messages::MessageTopicOperation,
     CLType, CLTyped, CLValue, EntityEntryPoint as EntryPoint, 
     EntryPointAccess, EntryPointPayment, EntryPointType, EntryPoints,
-    Key, Parameter, URef, U256,
+    Key, NamedKeys, Parameter, URef, U256,
     ApiError,
 };
+use events::BastionEvent;
 
 // Dictionaries
 const DICT_BALANCES: &str = "balances";
 const DICT_NULLIFIERS: &str = "nullifiers";
 const KEY_TOTAL_ORDERS: &str = "total_orders";
+const KEY_ADMIN: &str = "admin";
+const KEY_PAUSED: &str = "paused";
+
+// Message topic
+pub(crate) const EVENTS_TOPIC: &str = "events";
 
 // Error codes  
 const ERROR_NULLIFIER_USED: u16 = 2;
+const ERROR_NOT_ADMIN: u16 = 3;
+const ERROR_PAUSED: u16 = 4;
 
 fn get_dict(name: &str) -> URef {
     runtime::get_key(name)
@@ -29,6 +41,24 @@ fn get_dict(name: &str) -> URef {
         .unwrap_or_revert()
 }
 
+/// Reads the value stored under the named key `name`.
+fn read_value<T: CLTyped + FromBytes>(name: &str) -> T {
+    let uref = runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Overwrites the value stored under the named key `name`.
+fn write_value<T: CLTyped + ToBytes>(name: &str, value: T) {
+    let uref = runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::write(uref, value);
+}
+
 fn bytes_to_hex(bytes: &[u8]) -> String {
     use alloc::format;
     let mut s = String::new();
@@ -38,6 +68,19 @@ fn bytes_to_hex(bytes: &[u8]) -> String {
     s
 }
 
+fn ensure_admin() {
+    let admin: Key = read_value(KEY_ADMIN);
+    if Key::from(runtime::get_caller()) != admin {
+        runtime::revert(ApiError::User(ERROR_NOT_ADMIN));
+    }
+}
+
+fn ensure_not_paused() {
+    if read_value::<bool>(KEY_PAUSED) {
+        runtime::revert(ApiError::User(ERROR_PAUSED));
+    }
+}
+
 // ============================================================================
 // Initialization
 // ============================================================================
@@ -73,6 +116,8 @@ pub extern "C" fn deposit_cspr() {
 
 #[no_mangle]
 pub extern "C" fn submit_order() {
+    ensure_not_paused();
+
     let is_cspr: bool = runtime::get_named_arg("is_cspr");
     let amount: U256 = runtime::get_named_arg("amount");
     let commitment: Vec<u8> = runtime::get_named_arg("commitment");
@@ -94,12 +139,37 @@ pub extern "C" fn submit_order() {
     storage::dictionary_put(nullifier_dict, &commitment_key, true);
     
     // Update total orders
-    let total_uref = runtime::get_key(KEY_TOTAL_ORDERS)
-        .unwrap_or_revert()
-        .into_uref()
-        .unwrap_or_revert();
-    let total: u64 = storage::read(total_uref).unwrap_or_revert().unwrap_or(0);
-    storage::write(total_uref, total + 1);
+    let total: u64 = read_value(KEY_TOTAL_ORDERS);
+    write_value(KEY_TOTAL_ORDERS, total + 1);
+}
+
+// ============================================================================
+// Emergency Stop
+// ============================================================================
+
+/// Admin EntryPoint halting order submission. Deposits and balance reads are unaffected. There is
+/// no withdrawal entry point yet, so a pause does not stop users from anything they could
+/// otherwise do to get funds out.
+#[no_mangle]
+pub extern "C" fn pause() {
+    set_paused(true);
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    set_paused(false);
+}
+
+fn set_paused(paused: bool) {
+    ensure_admin();
+    write_value(KEY_PAUSED, paused);
+
+    let admin = Key::from(runtime::get_caller());
+    events::emit_event(if paused {
+        BastionEvent::Paused(events::Paused { admin })
+    } else {
+        BastionEvent::Unpaused(events::Unpaused { admin })
+    });
 }
 
 // ============================================================================
@@ -121,15 +191,18 @@ pub extern "C" fn get_balance() {
 
 #[no_mangle]
 pub extern "C" fn get_total_orders() {
-    let total_uref = runtime::get_key(KEY_TOTAL_ORDERS)
-        .unwrap_or_revert()
-        .into_uref()
-        .unwrap_or_revert();
-    let total: u64 = storage::read(total_uref).unwrap_or_revert().unwrap_or(0);
+    let total: u64 = read_value(KEY_TOTAL_ORDERS);
     
     runtime::ret(CLValue::from_t(total).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn is_paused() {
+    let paused: bool = read_value(KEY_PAUSED);
+    
+    runtime::ret(CLValue::from_t(paused).unwrap_or_revert());
+}
+
 // ============================================================================
 // Contract Installation
 // ============================================================================
@@ -188,6 +261,41 @@ pub extern "C" fn call() {
         EntryPointPayment::Caller,
     ));
 
-    let (contract_hash, _) = storage::new_contract(entry_points, None, None, None, None);
+    for name in ["pause", "unpause"] {
+        entry_points.add_entry_point(EntryPoint::new(
+            String::from(name),
+            alloc::vec![],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Called,
+            EntryPointPayment::Caller,
+        ));
+    }
+    
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("is_paused"),
+        alloc::vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        String::from(KEY_ADMIN),
+        storage::new_uref(Key::from(runtime::get_caller())).into(),
+    );
+    named_keys.insert(String::from(KEY_PAUSED), storage::new_uref(false).into());
+
+    let message_topics = BTreeMap::from([(String::from(EVENTS_TOPIC), MessageTopicOperation::Add)]);
+
+    let (contract_hash, _) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        None,
+        None,
+        Some(message_topics),
+    );
     runtime::put_key("bastion_dark_pool", contract_hash.into());
 }
@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod pause;
+#[cfg(test)]
+mod utility;
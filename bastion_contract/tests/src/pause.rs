@@ -0,0 +1,82 @@
+use crate::utility::{
+    constants::{ERROR_NOT_ADMIN, ERROR_PAUSED},
+    installer_request_builders::{assert_user_error, get_test_account, query_stored_value, setup},
+};
+use casper_engine_test_support::{ExecuteRequest, ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{account::AccountHash, runtime_args, AddressableEntityHash, RuntimeArgs, U256};
+
+fn make_submit_order_request(bastion: AddressableEntityHash, commitment: u8) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        bastion,
+        "submit_order",
+        runtime_args! {
+            "is_cspr" => true,
+            "amount" => U256::from(1_000u64),
+            "commitment" => vec![commitment; 32],
+            "proof" => vec![0u8; 64],
+        },
+    )
+    .build()
+}
+
+fn make_admin_request(
+    sender: AccountHash,
+    bastion: AddressableEntityHash,
+    entry_point: &str,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(sender, bastion, entry_point, RuntimeArgs::new())
+        .build()
+}
+
+#[test]
+fn should_block_submit_order_while_paused() {
+    let (mut builder, bastion) = setup();
+
+    builder
+        .exec(make_admin_request(*DEFAULT_ACCOUNT_ADDR, bastion, "pause"))
+        .expect_success()
+        .commit();
+    let paused: bool = query_stored_value(&builder, bastion, "paused");
+    assert!(paused);
+
+    builder
+        .exec(make_submit_order_request(bastion, 1))
+        .expect_failure();
+    assert_user_error(&builder, ERROR_PAUSED);
+    let total_orders: u64 = query_stored_value(&builder, bastion, "total_orders");
+    assert_eq!(total_orders, 0);
+
+    builder
+        .exec(make_admin_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            bastion,
+            "unpause",
+        ))
+        .expect_success()
+        .commit();
+    builder
+        .exec(make_submit_order_request(bastion, 1))
+        .expect_success()
+        .commit();
+    let total_orders: u64 = query_stored_value(&builder, bastion, "total_orders");
+    assert_eq!(total_orders, 1);
+}
+
+#[test]
+fn should_reject_pause_from_non_admin() {
+    let (mut builder, bastion) = setup();
+    let (_, user_account_hash) = get_test_account(1);
+
+    builder
+        .exec(make_admin_request(user_account_hash, bastion, "pause"))
+        .expect_failure();
+    assert_user_error(&builder, ERROR_NOT_ADMIN);
+
+    let paused: bool = query_stored_value(&builder, bastion, "paused");
+    assert!(!paused);
+    builder
+        .exec(make_submit_order_request(bastion, 1))
+        .expect_success()
+        .commit();
+}
@@ -0,0 +1,6 @@
+pub const BASTION_CONTRACT_WASM: &str = "bastion_contract.wasm";
+
+pub const BASTION_KEY_NAME: &str = "bastion_dark_pool";
+
+pub const ERROR_NOT_ADMIN: u16 = 3;
+pub const ERROR_PAUSED: u16 = 4;
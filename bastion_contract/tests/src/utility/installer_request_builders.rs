@@ -0,0 +1,75 @@
+use super::constants::{BASTION_CONTRACT_WASM, BASTION_KEY_NAME};
+use casper_engine_test_support::{
+    utils::create_run_genesis_request, ExecuteRequestBuilder, LmdbWasmTestBuilder,
+    DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{
+    account::AccountHash, bytesrepr::FromBytes, AddressableEntityHash, ApiError, CLTyped, Key,
+    RuntimeArgs,
+};
+
+/// Installs the dark pool as the default account, which becomes its admin, and runs `init`.
+pub(crate) fn setup() -> (LmdbWasmTestBuilder, AddressableEntityHash) {
+    let mut builder = LmdbWasmTestBuilder::default();
+    builder
+        .run_genesis(create_run_genesis_request(DEFAULT_ACCOUNTS.to_vec()))
+        .commit();
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        BASTION_CONTRACT_WASM,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let bastion = builder
+        .get_entity_with_named_keys_by_account_hash(*DEFAULT_ACCOUNT_ADDR)
+        .unwrap()
+        .named_keys()
+        .get(BASTION_KEY_NAME)
+        .and_then(|key| key.into_entity_hash())
+        .expect("should have contract hash");
+
+    let init_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        bastion,
+        "init",
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(init_request).expect_success().commit();
+
+    (builder, bastion)
+}
+
+/// Key and account hash of the genesis account at `index` of `DEFAULT_ACCOUNTS`.
+pub(crate) fn get_test_account(index: usize) -> (Key, AccountHash) {
+    let account_hash = DEFAULT_ACCOUNTS[index].public_key().to_account_hash();
+    (Key::Account(account_hash), account_hash)
+}
+
+pub(crate) fn query_stored_value<T: CLTyped + FromBytes>(
+    builder: &LmdbWasmTestBuilder,
+    contract_hash: AddressableEntityHash,
+    name: &str,
+) -> T {
+    let stored = builder.query(None, Key::Hash(contract_hash.value()), &[name.to_string()]);
+    let cl_value = stored
+        .expect("must have stored value")
+        .as_cl_value()
+        .cloned()
+        .expect("must have cl value");
+
+    cl_value.into_t::<T>().expect("must get value")
+}
+
+/// Asserts that the last request reverted with `User(expected)`.
+pub(crate) fn assert_user_error(builder: &LmdbWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{error:?}"
+    );
+}
@@ -76,7 +76,37 @@ export enum ERROR_CODES {
   /// Unable to retrieve the version contract hash key.
   MissingVersionContractKey = 60037,
   /// The provided version contract key is invalid.
-  InvalidVersionContractKey = 60038
+  InvalidVersionContractKey = 60038,
+  /// The permit deadline has passed.
+  PermitExpired = 60039,
+  /// The permit nonce is not the owner's next nonce.
+  InvalidPermitNonce = 60040,
+  /// The permit signature does not match the owner's public key.
+  InvalidPermitSignature = 60041,
+  /// The maximum supply provided is invalid or below the initial supply.
+  InvalidMaxSupply = 60042,
+  /// Minting would take the total supply above the maximum supply.
+  MaxSupplyExceeded = 60043,
+  /// Transfers, mints and burns are paused.
+  ContractPaused = 60044,
+  /// The list of accounts that can pause the contract is invalid.
+  InvalidPauserList = 60045,
+  /// The sender, recipient or spender account is frozen.
+  AccountFrozen = 60046,
+  /// The roles given contain a flag that matches no role.
+  InvalidRoles = 60047,
+  /// The change would remove the Admin role from the last account holding it.
+  CannotRemoveLastAdmin = 60048,
+  /// The admin count given on upgrade is invalid.
+  InvalidAdminCount = 60049,
+  /// The caller is not the admin proposed by a pending admin handover.
+  NotProposedAdmin = 60050,
+  /// The minter has not enough minter allowance left for the mint.
+  MinterAllowanceExceeded = 60051,
+  /// The admins of the token are not counted, so no Admin role can be removed.
+  AdminCountUnknown = 60052,
+  /// The list of badge holders to migrate is invalid.
+  InvalidBadgeHolderList = 60053
 }
 
 export class ContractError extends Error {
@@ -9,7 +9,15 @@ export enum CEP18_EVENTS {
   Transfer = 'Transfer',
   TransferFrom = 'TransferFrom',
   ChangeSecurity = 'ChangeSecurity',
-  ChangeEventsMode = 'ChangeEventsMode'
+  ChangeEventsMode = 'ChangeEventsMode',
+  Paused = 'Paused',
+  Unpaused = 'Unpaused',
+  Frozen = 'Frozen',
+  Unfrozen = 'Unfrozen',
+  RolesGranted = 'RolesGranted',
+  RolesRevoked = 'RolesRevoked',
+  AdminProposed = 'AdminProposed',
+  MinterAllowanceChanged = 'MinterAllowanceChanged'
 }
 
 type EventName = keyof typeof CEP18_EVENTS;
@@ -42,6 +50,14 @@ export type CEP18Event = Event<
   | TransferFrom
   | ChangeSecurity
   | ChangeEventsMode
+  | Paused
+  | Unpaused
+  | Frozen
+  | Unfrozen
+  | RolesGranted
+  | RolesRevoked
+  | AdminProposed
+  | MinterAllowanceChanged
 >;
 
 export type EventsMap = {
@@ -54,6 +70,14 @@ export type EventsMap = {
   TransferFrom: WithTransactionInfo<Event<TransferFrom>>;
   ChangeSecurity: WithTransactionInfo<Event<ChangeSecurity>>;
   ChangeEventsMode: WithTransactionInfo<Event<ChangeEventsMode>>;
+  Paused: WithTransactionInfo<Event<Paused>>;
+  Unpaused: WithTransactionInfo<Event<Unpaused>>;
+  Frozen: WithTransactionInfo<Event<Frozen>>;
+  Unfrozen: WithTransactionInfo<Event<Unfrozen>>;
+  RolesGranted: WithTransactionInfo<Event<RolesGranted>>;
+  RolesRevoked: WithTransactionInfo<Event<RolesRevoked>>;
+  AdminProposed: WithTransactionInfo<Event<AdminProposed>>;
+  MinterAllowanceChanged: WithTransactionInfo<Event<MinterAllowanceChanged>>;
 };
 
 export type Mint = { recipient: CLValue; amount: CLValue };
@@ -97,3 +121,44 @@ export type ChangeSecurity = {
 export type ChangeEventsMode = {
   events_mode: CLValue;
 };
+
+export type Paused = {
+  pauser: CLValue;
+};
+
+export type Unpaused = {
+  unpauser: CLValue;
+};
+
+export type Frozen = {
+  admin: CLValue;
+  frozen: CLValue;
+};
+
+export type Unfrozen = {
+  admin: CLValue;
+  unfrozen: CLValue;
+};
+
+export type RolesGranted = {
+  admin: CLValue;
+  account: CLValue;
+  granted: CLValue;
+};
+
+export type RolesRevoked = {
+  admin: CLValue;
+  account: CLValue;
+  revoked: CLValue;
+};
+
+export type AdminProposed = {
+  proposer: CLValue;
+  proposed_admin: CLValue;
+};
+
+export type MinterAllowanceChanged = {
+  admin: CLValue;
+  minter: CLValue;
+  minter_allowance: CLValue;
+};
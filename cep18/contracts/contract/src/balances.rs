@@ -4,7 +4,7 @@ use crate::{
     error::Cep18Error,
     utils::{base64_encode, get_dictionary_value_from_key, set_dictionary_value_for_key},
 };
-use alloc::string::String;
+use alloc::{collections::BTreeMap, string::String};
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_types::{bytesrepr::ToBytes, Key, U256};
 
@@ -47,22 +47,47 @@ pub fn transfer_balance(sender: Key, recipient: Key, amount: U256) -> Result<(),
     if sender == recipient || amount.is_zero() {
         return Ok(());
     }
+    transfer_balances(sender, &[(recipient, amount)])
+}
+
+/// Transfer tokens from the `sender` to each of the `transfers` recipients, debiting the sender
+/// once for the sum of the amounts.
+///
+/// This function does not validate the sender nor recipients, and the sender must not be one of
+/// the recipients. Check sender and recipients before using this function. Nothing is written if
+/// it returns an error.
+pub fn transfer_balances(sender: Key, transfers: &[(Key, U256)]) -> Result<(), Cep18Error> {
+    let total_amount = transfers
+        .iter()
+        .try_fold(U256::zero(), |total, (_, amount)| {
+            total.checked_add(*amount)
+        })
+        .ok_or(Cep18Error::Overflow)?;
+
     let new_sender_balance = {
         let sender_balance = read_balance_from(sender);
         sender_balance
-            .checked_sub(amount)
+            .checked_sub(total_amount)
             .ok_or(Cep18Error::InsufficientBalance)?
     };
 
-    let new_recipient_balance = {
-        let recipient_balance = read_balance_from(recipient);
-        recipient_balance
+    // Recipients may repeat, so their new balances accumulate before anything is written.
+    let mut new_recipient_balances: BTreeMap<Key, U256> = BTreeMap::new();
+    for &(recipient, amount) in transfers {
+        let recipient_balance = match new_recipient_balances.get(&recipient) {
+            Some(balance) => *balance,
+            None => read_balance_from(recipient),
+        };
+        let new_recipient_balance = recipient_balance
             .checked_add(amount)
-            .ok_or(Cep18Error::Overflow)?
-    };
+            .ok_or(Cep18Error::Overflow)?;
+        new_recipient_balances.insert(recipient, new_recipient_balance);
+    }
 
     write_balance_to(sender, new_sender_balance);
-    write_balance_to(recipient, new_recipient_balance);
+    for (recipient, new_recipient_balance) in new_recipient_balances {
+        write_balance_to(recipient, new_recipient_balance);
+    }
 
     Ok(())
 }
@@ -6,38 +6,68 @@ pub const PREFIX_CONTRACT_NAME: &str = "contract_hash";
 pub const PREFIX_CONTRACT_VERSION: &str = "contract_version";
 pub const PREFIX_CONTRACT_PACKAGE_NAME: &str = "contract_package";
 
+pub const ENTRY_POINT_ACCEPT_ADMIN: &str = "accept_admin";
+pub const ENTRY_POINT_ADMIN_COUNT: &str = "admin_count";
 pub const ENTRY_POINT_ALLOWANCE: &str = "allowance";
 pub const ENTRY_POINT_APPROVE: &str = "approve";
 pub const ENTRY_POINT_BALANCE_OF: &str = "balance_of";
+pub const ENTRY_POINT_BATCH_TRANSFER: &str = "batch_transfer";
 pub const ENTRY_POINT_BURN: &str = "burn";
+pub const ENTRY_POINT_BURN_FROM: &str = "burn_from";
 pub const ENTRY_POINT_CHANGE_EVENTS_MODE: &str = "change_events_mode";
 pub const ENTRY_POINT_CHANGE_SECURITY: &str = "change_security";
 pub const ENTRY_POINT_DECIMALS: &str = "decimals";
 pub const ENTRY_POINT_DECREASE_ALLOWANCE: &str = "decrease_allowance";
+pub const ENTRY_POINT_FREEZE: &str = "freeze";
+pub const ENTRY_POINT_GRANT_ROLES: &str = "grant_roles";
 pub const ENTRY_POINT_INCREASE_ALLOWANCE: &str = "increase_allowance";
 pub const ENTRY_POINT_INIT: &str = "init";
+pub const ENTRY_POINT_MAX_SUPPLY: &str = "max_supply";
+pub const ENTRY_POINT_MIGRATE: &str = "migrate";
 pub const ENTRY_POINT_MINT: &str = "mint";
+pub const ENTRY_POINT_MINTER_ALLOWANCE: &str = "minter_allowance";
 pub const ENTRY_POINT_NAME: &str = "name";
+pub const ENTRY_POINT_NONCE_OF: &str = "nonce_of";
+pub const ENTRY_POINT_PAUSE: &str = "pause";
+pub const ENTRY_POINT_PERMIT: &str = "permit";
+pub const ENTRY_POINT_PROPOSE_ADMIN: &str = "propose_admin";
+pub const ENTRY_POINT_REVOKE_ROLES: &str = "revoke_roles";
+pub const ENTRY_POINT_ROLES_OF: &str = "roles_of";
+pub const ENTRY_POINT_SET_MINTER_ALLOWANCE: &str = "set_minter_allowance";
 pub const ENTRY_POINT_SYMBOL: &str = "symbol";
 pub const ENTRY_POINT_TOTAL_SUPPLY: &str = "total_supply";
 pub const ENTRY_POINT_TRANSFER: &str = "transfer";
 pub const ENTRY_POINT_TRANSFER_FROM: &str = "transfer_from";
+pub const ENTRY_POINT_UNFREEZE: &str = "unfreeze";
+pub const ENTRY_POINT_UNPAUSE: &str = "unpause";
 
+pub const ARG_ACCOUNT: &str = "account";
 pub const ARG_ADDRESS: &str = "address";
+pub const ARG_ADMIN_COUNT: &str = "admin_count";
 pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_CONTRACT_HASH: &str = "contract_hash";
 pub const ARG_DATA: &str = "data";
+pub const ARG_DEADLINE: &str = "deadline";
 pub const ARG_DECIMALS: &str = "decimals";
 pub const ARG_ENABLE_MINT_BURN: &str = "enable_mint_burn";
 pub const ARG_EVENTS: &str = "events";
 pub const ARG_EVENTS_MODE: &str = "events_mode";
 pub const ARG_FROM: &str = "from";
 pub const ARG_ID: &str = "id";
+pub const ARG_MAX_SUPPLY: &str = "max_supply";
+pub const ARG_MINTER: &str = "minter";
+pub const ARG_MINTER_ALLOWANCE: &str = "minter_allowance";
 pub const ARG_NAME: &str = "name";
+pub const ARG_NEW_ADMIN: &str = "new_admin";
+pub const ARG_NONCE: &str = "nonce";
 pub const ARG_OPERATOR: &str = "operator";
 pub const ARG_OWNER: &str = "owner";
+pub const ARG_OWNER_PUBLIC_KEY: &str = "owner_public_key";
 pub const ARG_PACKAGE_HASH: &str = "package_hash";
 pub const ARG_RECIPIENT: &str = "recipient";
+pub const ARG_RECIPIENTS: &str = "recipients";
+pub const ARG_ROLES: &str = "roles";
+pub const ARG_SIGNATURE: &str = "signature";
 pub const ARG_SPENDER: &str = "spender";
 pub const ARG_SYMBOL: &str = "symbol";
 pub const ARG_TO: &str = "to";
@@ -46,7 +76,16 @@ pub const ARG_TOTAL_SUPPLY: &str = "total_supply";
 pub const ADMIN_LIST: &str = "admin_list";
 pub const MINTER_LIST: &str = "minter_list";
 pub const NONE_LIST: &str = "none_list";
+pub const PAUSER_LIST: &str = "pauser_list";
+pub const BADGE_HOLDER_LIST: &str = "badge_holder_list";
+
+pub const PAUSED: &str = "paused";
+pub const PENDING_ADMIN: &str = "pending_admin";
 
 pub const DICT_ALLOWANCES: &str = "allowances";
 pub const DICT_BALANCES: &str = "balances";
+pub const DICT_FROZEN: &str = "frozen";
+pub const DICT_MINTER_ALLOWANCES: &str = "minter_allowances";
+pub const DICT_NONCES: &str = "nonces";
+pub const DICT_ROLES: &str = "roles";
 pub const DICT_SECURITY_BADGES: &str = "security_badges";
@@ -1,17 +1,24 @@
 //! Contains definition of the entry points.
 use alloc::{string::String, vec, vec::Vec};
 use casper_types::{
-    CLType, CLTyped, EntityEntryPoint as EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
-    Key, Parameter, U256,
+    bytesrepr::Bytes, CLType, CLTyped, EntityEntryPoint as EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, PublicKey, U256,
 };
 
 use crate::constants::{
-    ARG_ADDRESS, ARG_AMOUNT, ARG_EVENTS_MODE, ARG_OWNER, ARG_RECIPIENT, ARG_SPENDER,
-    ENTRY_POINT_ALLOWANCE, ENTRY_POINT_APPROVE, ENTRY_POINT_BALANCE_OF, ENTRY_POINT_BURN,
+    ARG_ACCOUNT, ARG_ADDRESS, ARG_AMOUNT, ARG_DEADLINE, ARG_EVENTS_MODE, ARG_MINTER,
+    ARG_MINTER_ALLOWANCE, ARG_NEW_ADMIN, ARG_NONCE, ARG_OWNER, ARG_OWNER_PUBLIC_KEY, ARG_RECIPIENT,
+    ARG_RECIPIENTS, ARG_ROLES, ARG_SIGNATURE, ARG_SPENDER, ENTRY_POINT_ACCEPT_ADMIN,
+    ENTRY_POINT_ADMIN_COUNT, ENTRY_POINT_ALLOWANCE, ENTRY_POINT_APPROVE, ENTRY_POINT_BALANCE_OF,
+    ENTRY_POINT_BATCH_TRANSFER, ENTRY_POINT_BURN, ENTRY_POINT_BURN_FROM,
     ENTRY_POINT_CHANGE_EVENTS_MODE, ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_DECIMALS,
-    ENTRY_POINT_DECREASE_ALLOWANCE, ENTRY_POINT_INCREASE_ALLOWANCE, ENTRY_POINT_INIT,
-    ENTRY_POINT_MINT, ENTRY_POINT_NAME, ENTRY_POINT_SYMBOL, ENTRY_POINT_TOTAL_SUPPLY,
-    ENTRY_POINT_TRANSFER, ENTRY_POINT_TRANSFER_FROM,
+    ENTRY_POINT_DECREASE_ALLOWANCE, ENTRY_POINT_FREEZE, ENTRY_POINT_GRANT_ROLES,
+    ENTRY_POINT_INCREASE_ALLOWANCE, ENTRY_POINT_INIT, ENTRY_POINT_MAX_SUPPLY, ENTRY_POINT_MIGRATE,
+    ENTRY_POINT_MINT, ENTRY_POINT_MINTER_ALLOWANCE, ENTRY_POINT_NAME, ENTRY_POINT_NONCE_OF,
+    ENTRY_POINT_PAUSE, ENTRY_POINT_PERMIT, ENTRY_POINT_PROPOSE_ADMIN, ENTRY_POINT_REVOKE_ROLES,
+    ENTRY_POINT_ROLES_OF, ENTRY_POINT_SET_MINTER_ALLOWANCE, ENTRY_POINT_SYMBOL,
+    ENTRY_POINT_TOTAL_SUPPLY, ENTRY_POINT_TRANSFER, ENTRY_POINT_TRANSFER_FROM,
+    ENTRY_POINT_UNFREEZE, ENTRY_POINT_UNPAUSE,
 };
 
 /// Returns the `name` entry point.
@@ -84,6 +91,37 @@ pub fn approve() -> EntryPoint {
     )
 }
 
+/// Returns the `permit` entry point.
+pub fn permit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_PERMIT),
+        vec![
+            Parameter::new(ARG_OWNER_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(ARG_SPENDER, Key::cl_type()),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+            Parameter::new(ARG_NONCE, u64::cl_type()),
+            Parameter::new(ARG_DEADLINE, u64::cl_type()),
+            Parameter::new(ARG_SIGNATURE, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `nonce_of` entry point.
+pub fn nonce_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_NONCE_OF),
+        vec![Parameter::new(ARG_OWNER, Key::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
 /// Returns the `increase_allowance` entry point.
 pub fn increase_allowance() -> EntryPoint {
     EntryPoint::new(
@@ -129,6 +167,21 @@ pub fn transfer() -> EntryPoint {
     )
 }
 
+/// Returns the `batch_transfer` entry point.
+pub fn batch_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_BATCH_TRANSFER),
+        vec![Parameter::new(
+            ARG_RECIPIENTS,
+            Vec::<(Key, U256)>::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
 /// Returns the `balance_of` entry point.
 pub fn balance_of() -> EntryPoint {
     EntryPoint::new(
@@ -153,6 +206,54 @@ pub fn total_supply() -> EntryPoint {
     )
 }
 
+/// Returns the `propose_admin` entry point.
+pub fn propose_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_PROPOSE_ADMIN),
+        vec![Parameter::new(ARG_NEW_ADMIN, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `accept_admin` entry point.
+pub fn accept_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_ACCEPT_ADMIN),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `admin_count` entry point.
+pub fn admin_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_ADMIN_COUNT),
+        Vec::new(),
+        Option::<u32>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `max_supply` entry point.
+pub fn max_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_MAX_SUPPLY),
+        Vec::new(),
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
 /// Returns the `decimals` entry point.
 pub fn decimals() -> EntryPoint {
     EntryPoint::new(
@@ -180,6 +281,48 @@ pub fn burn() -> EntryPoint {
     )
 }
 
+/// Returns the `set_minter_allowance` entry point.
+pub fn set_minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_SET_MINTER_ALLOWANCE),
+        vec![
+            Parameter::new(ARG_MINTER, Key::cl_type()),
+            Parameter::new(ARG_MINTER_ALLOWANCE, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `minter_allowance` entry point.
+pub fn minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_MINTER_ALLOWANCE),
+        vec![Parameter::new(ARG_MINTER, Key::cl_type())],
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `burn_from` entry point.
+pub fn burn_from() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_BURN_FROM),
+        vec![
+            Parameter::new(ARG_OWNER, Key::cl_type()),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
 /// Returns the `mint` entry point.
 pub fn mint() -> EntryPoint {
     EntryPoint::new(
@@ -217,6 +360,7 @@ pub fn change_security() -> EntryPoint {
             - "admin_list" : Vec<Key>
             - "minter_list" : Vec<Key>
             - "none_list" : Vec<Key>
+            - "pauser_list" : Vec<Key>
             */
         ],
         CLType::Unit,
@@ -226,6 +370,96 @@ pub fn change_security() -> EntryPoint {
     )
 }
 
+/// Returns the `pause` entry point.
+pub fn pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_PAUSE),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `unpause` entry point.
+pub fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_UNPAUSE),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `freeze` entry point.
+pub fn freeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_FREEZE),
+        vec![Parameter::new(ARG_ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `unfreeze` entry point.
+pub fn unfreeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_UNFREEZE),
+        vec![Parameter::new(ARG_ACCOUNT, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `grant_roles` entry point.
+pub fn grant_roles() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_GRANT_ROLES),
+        vec![
+            Parameter::new(ARG_ACCOUNT, Key::cl_type()),
+            Parameter::new(ARG_ROLES, u8::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `revoke_roles` entry point.
+pub fn revoke_roles() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_REVOKE_ROLES),
+        vec![
+            Parameter::new(ARG_ACCOUNT, Key::cl_type()),
+            Parameter::new(ARG_ROLES, u8::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
+/// Returns the `roles_of` entry point.
+pub fn roles_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_ROLES_OF),
+        vec![Parameter::new(ARG_ACCOUNT, Key::cl_type())],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
 /// Returns the `init` entry point.
 pub fn init() -> EntryPoint {
     EntryPoint::new(
@@ -238,6 +472,23 @@ pub fn init() -> EntryPoint {
     )
 }
 
+/// Returns the `migrate` entry point.
+pub fn migrate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ENTRY_POINT_MIGRATE),
+        vec![
+            // Optional Arguments (can be added or omitted when calling):
+            /*
+            - "badge_holder_list" : Vec<Key>
+            */
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        casper_types::EntryPointPayment::Caller,
+    )
+}
+
 /// Returns the default set of CEP-18 token entry points.
 pub fn generate_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
@@ -246,16 +497,34 @@ pub fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(symbol());
     entry_points.add_entry_point(decimals());
     entry_points.add_entry_point(total_supply());
+    entry_points.add_entry_point(max_supply());
     entry_points.add_entry_point(balance_of());
     entry_points.add_entry_point(transfer());
+    entry_points.add_entry_point(batch_transfer());
     entry_points.add_entry_point(approve());
     entry_points.add_entry_point(allowance());
+    entry_points.add_entry_point(permit());
+    entry_points.add_entry_point(nonce_of());
     entry_points.add_entry_point(decrease_allowance());
     entry_points.add_entry_point(increase_allowance());
     entry_points.add_entry_point(transfer_from());
     entry_points.add_entry_point(change_security());
     entry_points.add_entry_point(burn());
+    entry_points.add_entry_point(burn_from());
     entry_points.add_entry_point(mint());
+    entry_points.add_entry_point(set_minter_allowance());
+    entry_points.add_entry_point(minter_allowance());
     entry_points.add_entry_point(change_events_mode());
+    entry_points.add_entry_point(pause());
+    entry_points.add_entry_point(unpause());
+    entry_points.add_entry_point(freeze());
+    entry_points.add_entry_point(unfreeze());
+    entry_points.add_entry_point(grant_roles());
+    entry_points.add_entry_point(revoke_roles());
+    entry_points.add_entry_point(roles_of());
+    entry_points.add_entry_point(admin_count());
+    entry_points.add_entry_point(propose_admin());
+    entry_points.add_entry_point(accept_admin());
+    entry_points.add_entry_point(migrate());
     entry_points
 }
@@ -1,4 +1,4 @@
-use crate::security::SecurityBadge;
+use crate::security::{Roles, SecurityBadge};
 #[cfg(feature = "contract-support")]
 use crate::utils::get_stored_value;
 #[cfg(feature = "contract-support")]
@@ -12,12 +12,15 @@ use alloc::collections::BTreeMap;
 use alloc::string::String;
 #[cfg(feature = "contract-support")]
 use casper_contract::{
-    contract_api::runtime::{emit_message, get_key},
+    contract_api::{
+        runtime::{emit_message, get_key, put_key},
+        storage::new_uref,
+    },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_event_standard::Event;
 #[cfg(feature = "contract-support")]
-use casper_event_standard::{emit, init, Schemas, EVENTS_DICT};
+use casper_event_standard::{emit, init, Schemas, EVENTS_DICT, EVENTS_SCHEMA};
 #[cfg(feature = "contract-support")]
 use casper_types::{bytesrepr::Bytes, contract_messages::MessagePayload};
 use casper_types::{Key, U256};
@@ -51,6 +54,14 @@ pub enum Event {
     TransferFrom(TransferFrom),
     ChangeSecurity(ChangeSecurity),
     ChangeEventsMode(ChangeEventsMode),
+    Paused(Paused),
+    Unpaused(Unpaused),
+    Frozen(Frozen),
+    Unfrozen(Unfrozen),
+    RolesGranted(RolesGranted),
+    RolesRevoked(RolesRevoked),
+    AdminProposed(AdminProposed),
+    MinterAllowanceChanged(MinterAllowanceChanged),
 }
 
 impl Event {
@@ -129,6 +140,55 @@ pub struct ChangeEventsMode {
     pub events_mode: u8,
 }
 
+#[derive(Serialize, Deserialize, Event, Debug, PartialEq, Eq)]
+pub struct Paused {
+    pub pauser: Key,
+}
+
+#[derive(Serialize, Deserialize, Event, Debug, PartialEq, Eq)]
+pub struct Unpaused {
+    pub unpauser: Key,
+}
+
+#[derive(Serialize, Deserialize, Event, Debug, PartialEq, Eq)]
+pub struct Frozen {
+    pub admin: Key,
+    pub frozen: Key,
+}
+
+#[derive(Serialize, Deserialize, Event, Debug, PartialEq, Eq)]
+pub struct Unfrozen {
+    pub admin: Key,
+    pub unfrozen: Key,
+}
+
+#[derive(Serialize, Deserialize, Event, Debug, PartialEq, Eq)]
+pub struct RolesGranted {
+    pub admin: Key,
+    pub account: Key,
+    pub granted: Roles,
+}
+
+#[derive(Serialize, Deserialize, Event, Debug, PartialEq, Eq)]
+pub struct RolesRevoked {
+    pub admin: Key,
+    pub account: Key,
+    pub revoked: Roles,
+}
+
+#[derive(Serialize, Deserialize, Event, Debug, PartialEq, Eq)]
+pub struct AdminProposed {
+    pub proposer: Key,
+    pub proposed_admin: Key,
+}
+
+#[derive(Serialize, Deserialize, Event, Debug, PartialEq, Eq)]
+pub struct MinterAllowanceChanged {
+    pub admin: Key,
+    pub minter: Key,
+    pub minter_allowance: U256,
+}
+
 #[cfg(feature = "contract-support")]
 fn ces(event: Event) {
     match event {
@@ -141,25 +201,54 @@ fn ces(event: Event) {
         Event::TransferFrom(ev) => emit(ev),
         Event::ChangeSecurity(ev) => emit(ev),
         Event::ChangeEventsMode(ev) => emit(ev),
+        Event::Paused(ev) => emit(ev),
+        Event::Unpaused(ev) => emit(ev),
+        Event::Frozen(ev) => emit(ev),
+        Event::Unfrozen(ev) => emit(ev),
+        Event::RolesGranted(ev) => emit(ev),
+        Event::RolesRevoked(ev) => emit(ev),
+        Event::AdminProposed(ev) => emit(ev),
+        Event::MinterAllowanceChanged(ev) => emit(ev),
     }
 }
 
+#[cfg(feature = "contract-support")]
+fn schemas() -> Schemas {
+    Schemas::new()
+        .with::<Mint>()
+        .with::<Burn>()
+        .with::<SetAllowance>()
+        .with::<IncreaseAllowance>()
+        .with::<DecreaseAllowance>()
+        .with::<Transfer>()
+        .with::<TransferFrom>()
+        .with::<ChangeSecurity>()
+        .with::<ChangeEventsMode>()
+        .with::<Paused>()
+        .with::<Unpaused>()
+        .with::<Frozen>()
+        .with::<Unfrozen>()
+        .with::<RolesGranted>()
+        .with::<RolesRevoked>()
+        .with::<AdminProposed>()
+        .with::<MinterAllowanceChanged>()
+}
+
+/// Registers the CES schemas of every event when the token is in CES mode.
+///
+/// Tokens that already have an events dictionary keep their recorded events; only their schemas
+/// are replaced, so events added since they were installed can be recorded.
 #[cfg(feature = "contract-support")]
 pub fn init_events() {
     let events_mode: EventsMode = EventsMode::try_from(get_stored_value::<u8>(ARG_EVENTS_MODE))
         .unwrap_or_revert_with(Cep18Error::InvalidEventsMode);
 
-    if EventsMode::CES == events_mode && get_key(EVENTS_DICT).is_none() {
-        let schemas = Schemas::new()
-            .with::<Mint>()
-            .with::<Burn>()
-            .with::<SetAllowance>()
-            .with::<IncreaseAllowance>()
-            .with::<DecreaseAllowance>()
-            .with::<Transfer>()
-            .with::<TransferFrom>()
-            .with::<ChangeSecurity>()
-            .with::<ChangeEventsMode>();
-        init(schemas);
+    if EventsMode::CES != events_mode {
+        return;
+    }
+    if get_key(EVENTS_DICT).is_none() {
+        init(schemas());
+    } else {
+        put_key(EVENTS_SCHEMA, new_uref(schemas()).into());
     }
 }
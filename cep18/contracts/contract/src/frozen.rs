@@ -0,0 +1,50 @@
+//! Implementation of frozen accounts.
+use crate::{
+    constants::DICT_FROZEN,
+    error::Cep18Error,
+    utils::{base64_encode, get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+use alloc::string::String;
+use casper_contract::{
+    contract_api::{
+        runtime::{get_key, revert},
+        storage::new_dictionary,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key};
+
+/// Creates a dictionary item key for an account, the same way as for balances.
+fn make_dictionary_item_key(account: Key) -> String {
+    let preimage = account
+        .to_bytes()
+        .unwrap_or_revert_with(Cep18Error::FailedToConvertBytes);
+    base64_encode(preimage)
+}
+
+/// Writes whether an account is frozen, creating the frozen dictionary on the first freeze of a
+/// token upgraded from a version without it.
+pub fn write_frozen_to(account: Key, frozen: bool) {
+    if get_key(DICT_FROZEN).is_none() {
+        new_dictionary(DICT_FROZEN).unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    }
+    set_dictionary_value_for_key(DICT_FROZEN, &make_dictionary_item_key(account), &frozen)
+}
+
+/// Reads whether an account is frozen.
+///
+/// Accounts that were never frozen are not.
+pub fn read_frozen_from(account: Key) -> bool {
+    if get_key(DICT_FROZEN).is_none() {
+        return false;
+    }
+    get_dictionary_value_from_key(DICT_FROZEN, &make_dictionary_item_key(account))
+        .unwrap_or_default()
+}
+
+/// Reverts with [`Cep18Error::AccountFrozen`] if any of the accounts is frozen.
+pub fn ensure_not_frozen(accounts: &[Key]) {
+    if accounts.iter().any(|account| read_frozen_from(*account)) {
+        revert(Cep18Error::AccountFrozen);
+    }
+}
@@ -7,6 +7,7 @@ pub mod entry_points;
 pub mod error;
 pub mod events;
 pub mod modalities;
+pub mod permit;
 pub mod security;
 
 #[cfg(feature = "contract-support")]
@@ -14,4 +15,10 @@ pub mod allowances;
 #[cfg(feature = "contract-support")]
 pub mod balances;
 #[cfg(feature = "contract-support")]
+pub mod frozen;
+#[cfg(feature = "contract-support")]
+pub mod minter_allowances;
+#[cfg(feature = "contract-support")]
+pub mod token;
+#[cfg(feature = "contract-support")]
 pub mod utils;
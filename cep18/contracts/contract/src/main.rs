@@ -7,193 +7,204 @@ use alloc::{
     collections::BTreeMap,
     format,
     string::{String, ToString},
-    vec,
     vec::Vec,
 };
 use casper_contract::{
     contract_api::{
         runtime::{self, call_contract, get_key, get_named_arg, put_key, revert},
-        storage::{self, dictionary_put, read},
+        storage::{self, read},
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    bytesrepr::ToBytes, contract_messages::MessageTopicOperation, contracts::ContractPackageHash,
-    runtime_args, AddressableEntityHash, CLValue, EntityAddr, Key, NamedKeys, U256,
+    bytesrepr::{self, Bytes},
+    contract_messages::MessageTopicOperation,
+    contracts::ContractPackageHash,
+    crypto::Signature,
+    runtime_args, AddressableEntityHash, CLValue, EntityAddr, Key, NamedKeys, PublicKey, U256,
 };
 use cep18::{
     allowances::{read_allowance_from, write_allowance_to},
-    balances::{read_balance_from, transfer_balance, write_balance_to},
+    balances::{read_balance_from, transfer_balances, write_balance_to},
     constants::{
-        ADMIN_LIST, ARG_ADDRESS, ARG_AMOUNT, ARG_CONTRACT_HASH, ARG_DECIMALS, ARG_ENABLE_MINT_BURN,
-        ARG_EVENTS, ARG_EVENTS_MODE, ARG_NAME, ARG_OWNER, ARG_PACKAGE_HASH, ARG_RECIPIENT,
-        ARG_SPENDER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, DICT_ALLOWANCES, DICT_BALANCES,
-        DICT_SECURITY_BADGES, ENTRY_POINT_CHANGE_EVENTS_MODE, ENTRY_POINT_INIT, MINTER_LIST,
-        NONE_LIST, PREFIX_ACCESS_KEY_NAME, PREFIX_CEP18, PREFIX_CONTRACT_NAME,
+        ADMIN_LIST, ARG_ACCOUNT, ARG_ADMIN_COUNT, ARG_AMOUNT, ARG_CONTRACT_HASH, ARG_DEADLINE,
+        ARG_DECIMALS, ARG_ENABLE_MINT_BURN, ARG_EVENTS, ARG_EVENTS_MODE, ARG_MAX_SUPPLY,
+        ARG_MINTER, ARG_MINTER_ALLOWANCE, ARG_NAME, ARG_NEW_ADMIN, ARG_NONCE, ARG_OWNER,
+        ARG_OWNER_PUBLIC_KEY, ARG_PACKAGE_HASH, ARG_RECIPIENTS, ARG_ROLES, ARG_SIGNATURE,
+        ARG_SPENDER, ARG_SYMBOL, ARG_TOTAL_SUPPLY, BADGE_HOLDER_LIST, DICT_ALLOWANCES,
+        DICT_BALANCES, DICT_FROZEN, DICT_MINTER_ALLOWANCES, DICT_NONCES, DICT_ROLES,
+        ENTRY_POINT_CHANGE_EVENTS_MODE, ENTRY_POINT_INIT, ENTRY_POINT_MIGRATE, MINTER_LIST,
+        NONE_LIST, PAUSED, PAUSER_LIST, PREFIX_ACCESS_KEY_NAME, PREFIX_CEP18, PREFIX_CONTRACT_NAME,
         PREFIX_CONTRACT_PACKAGE_NAME, PREFIX_CONTRACT_VERSION,
     },
     entry_points::generate_entry_points,
     error::Cep18Error,
     events::{
-        self, init_events, Burn, ChangeEventsMode, ChangeSecurity, DecreaseAllowance, Event,
-        IncreaseAllowance, Mint, SetAllowance, Transfer, TransferFrom,
+        self, init_events, AdminProposed, Burn, ChangeEventsMode, ChangeSecurity,
+        DecreaseAllowance, Event, Frozen, Mint, MinterAllowanceChanged, Paused, RolesGranted,
+        RolesRevoked, SetAllowance, Transfer, Unfrozen, Unpaused,
     },
+    frozen::{ensure_not_frozen, read_frozen_from, write_frozen_to},
+    minter_allowances::{read_minter_allowance, write_minter_allowance},
     modalities::EventsMode,
-    security::{change_sec_badge, sec_check, SecurityBadge},
+    permit::{permit_message, read_nonce_of, write_nonce_of},
+    security::{
+        change_sec_badge, migrate_badge_of, read_roles_of, sec_check, write_roles_of, Roles,
+        SecurityBadge,
+    },
+    token,
     utils::{
-        base64_encode, get_contract_version_key, get_immediate_caller,
+        clear_pending_admin, ensure_not_paused, get_contract_version_key, get_immediate_caller,
         get_optional_named_arg_with_user_errors, get_stored_value, get_uref_with_user_errors,
-        write_total_supply_to,
+        is_paused, read_admin_count, read_max_supply, read_pending_admin, write_paused,
+        write_pending_admin, write_total_supply_to,
     },
 };
 
 #[no_mangle]
 pub extern "C" fn name() {
-    runtime::ret(
-        CLValue::from_t(get_stored_value::<String>(ARG_NAME))
-            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
-    );
+    token::name();
 }
 
 #[no_mangle]
 pub extern "C" fn symbol() {
-    runtime::ret(
-        CLValue::from_t(get_stored_value::<String>(ARG_SYMBOL))
-            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
-    );
+    token::symbol();
 }
 
 #[no_mangle]
 pub extern "C" fn decimals() {
+    token::decimals();
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    token::total_supply();
+}
+
+#[no_mangle]
+pub extern "C" fn max_supply() {
     runtime::ret(
-        CLValue::from_t(get_stored_value::<u8>(ARG_DECIMALS))
+        CLValue::from_t(read_max_supply())
             .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
     );
 }
 
 #[no_mangle]
-pub extern "C" fn total_supply() {
+pub extern "C" fn admin_count() {
     runtime::ret(
-        CLValue::from_t(get_stored_value::<U256>(ARG_TOTAL_SUPPLY))
+        CLValue::from_t(read_admin_count())
             .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
     );
 }
 
 #[no_mangle]
 pub extern "C" fn balance_of() {
-    let address: Key = runtime::get_named_arg(ARG_ADDRESS);
-    let balance = read_balance_from(address);
-    runtime::ret(
-        CLValue::from_t(balance).unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
-    );
+    token::balance_of();
 }
 
 #[no_mangle]
 pub extern "C" fn allowance() {
-    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
-    let owner: Key = runtime::get_named_arg(ARG_OWNER);
-    let val: U256 = read_allowance_from(owner, spender);
-    runtime::ret(
-        CLValue::from_t(val).unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
-    );
+    token::allowance();
 }
 
 #[no_mangle]
 pub extern "C" fn approve() {
-    let caller = get_immediate_caller();
+    token::approve();
+}
+
+/// Sets a spender's allowance over the tokens of `owner_public_key`'s account, approved by a
+/// signature over [`permit_message`] instead of a deploy from the owner. Each permit carries the
+/// owner's next nonce, so it can be used only once, and expires after `deadline`.
+#[no_mangle]
+pub extern "C" fn permit() {
+    let owner_public_key: PublicKey = runtime::get_named_arg(ARG_OWNER_PUBLIC_KEY);
+    let owner = Key::Account(owner_public_key.to_account_hash());
     let spender: Key = runtime::get_named_arg(ARG_SPENDER);
-    if spender == caller {
+    if spender == owner {
         revert(Cep18Error::CannotTargetSelfUser);
     }
+    ensure_not_frozen(&[owner, spender]);
     let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
-    write_allowance_to(caller, spender, amount);
+    let nonce: u64 = runtime::get_named_arg(ARG_NONCE);
+    let deadline: u64 = runtime::get_named_arg(ARG_DEADLINE);
+    let signature: Bytes = runtime::get_named_arg(ARG_SIGNATURE);
+
+    if u64::from(runtime::get_blocktime()) > deadline {
+        revert(Cep18Error::PermitExpired);
+    }
+    if nonce != read_nonce_of(owner) {
+        revert(Cep18Error::InvalidPermitNonce);
+    }
+    let signature: Signature = bytesrepr::deserialize_from_slice(signature)
+        .unwrap_or_revert_with(Cep18Error::InvalidPermitSignature);
+    let package_hash = get_key(ARG_PACKAGE_HASH).unwrap_or_revert_with(Cep18Error::FailedToGetKey);
+    let message = permit_message(package_hash, owner, spender, amount, nonce, deadline);
+    runtime::verify_signature(&message, &signature, &owner_public_key)
+        .unwrap_or_revert_with(Cep18Error::InvalidPermitSignature);
+
+    write_nonce_of(owner, nonce + 1);
+    write_allowance_to(owner, spender, amount);
     events::record_event_dictionary(Event::SetAllowance(SetAllowance {
-        owner: caller,
+        owner,
         spender,
         allowance: amount,
     }))
 }
 
+#[no_mangle]
+pub extern "C" fn nonce_of() {
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    runtime::ret(
+        CLValue::from_t(read_nonce_of(owner))
+            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
 #[no_mangle]
 pub extern "C" fn decrease_allowance() {
-    let caller = get_immediate_caller();
-    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
-    if spender == caller {
-        revert(Cep18Error::CannotTargetSelfUser);
-    }
-    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
-    let current_allowance = read_allowance_from(caller, spender);
-    let new_allowance = current_allowance.saturating_sub(amount);
-    write_allowance_to(caller, spender, new_allowance);
-    events::record_event_dictionary(Event::DecreaseAllowance(DecreaseAllowance {
-        owner: caller,
-        spender,
-        decr_by: amount,
-        allowance: new_allowance,
-    }))
+    token::decrease_allowance();
 }
 
 #[no_mangle]
 pub extern "C" fn increase_allowance() {
-    let caller = get_immediate_caller();
-    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
-    if spender == caller {
-        revert(Cep18Error::CannotTargetSelfUser);
-    }
-    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
-    let current_allowance = read_allowance_from(caller, spender);
-    let new_allowance = current_allowance.saturating_add(amount);
-    write_allowance_to(caller, spender, new_allowance);
-    events::record_event_dictionary(Event::IncreaseAllowance(IncreaseAllowance {
-        owner: caller,
-        spender,
-        allowance: new_allowance,
-        inc_by: amount,
-    }))
+    token::increase_allowance();
 }
 
 #[no_mangle]
 pub extern "C" fn transfer() {
-    let caller = get_immediate_caller();
-    let recipient: Key = runtime::get_named_arg(ARG_RECIPIENT);
-    if caller == recipient {
-        revert(Cep18Error::CannotTargetSelfUser);
-    }
-    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
-    transfer_balance(caller, recipient, amount).unwrap_or_revert();
-    events::record_event_dictionary(Event::Transfer(Transfer {
-        sender: caller,
-        recipient,
-        amount,
-    }))
+    token::transfer();
 }
 
+/// Moves tokens from the caller to each of the `recipients`, with one debit of the caller's
+/// balance for the sum of the amounts. Reverts as a whole if any single transfer would.
 #[no_mangle]
-pub extern "C" fn transfer_from() {
+pub extern "C" fn batch_transfer() {
+    ensure_not_paused();
     let caller = get_immediate_caller();
-    let recipient: Key = runtime::get_named_arg(ARG_RECIPIENT);
-    let owner: Key = runtime::get_named_arg(ARG_OWNER);
-    if owner == recipient {
+    let recipients: Vec<(Key, U256)> = runtime::get_named_arg(ARG_RECIPIENTS);
+    if recipients.iter().any(|(recipient, _)| *recipient == caller) {
         revert(Cep18Error::CannotTargetSelfUser);
     }
-    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
-    if amount.is_zero() {
-        return;
+    ensure_not_frozen(&[caller]);
+    ensure_not_frozen(
+        &recipients
+            .iter()
+            .map(|(recipient, _)| *recipient)
+            .collect::<Vec<Key>>(),
+    );
+    transfer_balances(caller, &recipients).unwrap_or_revert();
+    for (recipient, amount) in recipients {
+        events::record_event_dictionary(Event::Transfer(Transfer {
+            sender: caller,
+            recipient,
+            amount,
+        }))
     }
+}
 
-    let spender_allowance: U256 = read_allowance_from(owner, caller);
-    let new_spender_allowance = spender_allowance
-        .checked_sub(amount)
-        .unwrap_or_revert_with(Cep18Error::InsufficientAllowance);
-
-    transfer_balance(owner, recipient, amount).unwrap_or_revert();
-    write_allowance_to(owner, caller, new_spender_allowance);
-    events::record_event_dictionary(Event::TransferFrom(TransferFrom {
-        spender: caller,
-        owner,
-        recipient,
-        amount,
-    }))
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    token::transfer_from();
 }
 
 #[no_mangle]
@@ -201,12 +212,23 @@ pub extern "C" fn mint() {
     if 0 == get_stored_value::<u8>(ARG_ENABLE_MINT_BURN) {
         revert(Cep18Error::MintBurnDisabled);
     }
+    ensure_not_paused();
 
-    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Minter]);
+    sec_check(Roles::ADMIN | Roles::MINTER);
 
     let owner: Key = runtime::get_named_arg(ARG_OWNER);
     let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
 
+    // Admins mint without limit, minters only within their quota, which is zero until set.
+    let caller = get_immediate_caller();
+    if !read_roles_of(caller).contains(Roles::ADMIN) {
+        let new_minter_allowance = read_minter_allowance(caller)
+            .unwrap_or_default()
+            .checked_sub(amount)
+            .unwrap_or_revert_with(Cep18Error::MinterAllowanceExceeded);
+        write_minter_allowance(caller, new_minter_allowance);
+    }
+
     let new_balance = {
         let balance = read_balance_from(owner);
         balance
@@ -220,6 +242,11 @@ pub extern "C" fn mint() {
             .ok_or(Cep18Error::Overflow)
             .unwrap_or_revert()
     };
+    if let Some(max_supply) = read_max_supply() {
+        if new_total_supply > max_supply {
+            revert(Cep18Error::MaxSupplyExceeded);
+        }
+    }
 
     write_balance_to(owner, new_balance);
     write_total_supply_to(new_total_supply);
@@ -230,19 +257,78 @@ pub extern "C" fn mint() {
     }));
 }
 
+/// Admin EntryPoint setting the amount of tokens a minter can still mint.
+#[no_mangle]
+pub extern "C" fn set_minter_allowance() {
+    sec_check(Roles::ADMIN);
+    let minter: Key = runtime::get_named_arg(ARG_MINTER);
+    let minter_allowance: U256 = runtime::get_named_arg(ARG_MINTER_ALLOWANCE);
+    write_minter_allowance(minter, minter_allowance);
+    events::record_event_dictionary(Event::MinterAllowanceChanged(MinterAllowanceChanged {
+        admin: get_immediate_caller(),
+        minter,
+        minter_allowance,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn minter_allowance() {
+    let minter: Key = runtime::get_named_arg(ARG_MINTER);
+    runtime::ret(
+        CLValue::from_t(read_minter_allowance(minter))
+            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
 #[no_mangle]
 pub extern "C" fn burn() {
     if 0 == get_stored_value::<u8>(ARG_ENABLE_MINT_BURN) {
         revert(Cep18Error::MintBurnDisabled);
     }
+    ensure_not_paused();
 
     let owner: Key = runtime::get_named_arg(ARG_OWNER);
-    let caller = get_immediate_caller();
-    if owner != caller {
+    if owner != get_immediate_caller() {
         revert(Cep18Error::InvalidBurnTarget);
     }
 
     let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    burn_balance(owner, amount);
+}
+
+/// Burner EntryPoint burning tokens of an owner on behalf of the caller, spending the allowance
+/// the owner gave it.
+#[no_mangle]
+pub extern "C" fn burn_from() {
+    if 0 == get_stored_value::<u8>(ARG_ENABLE_MINT_BURN) {
+        revert(Cep18Error::MintBurnDisabled);
+    }
+    sec_check(Roles::BURNER);
+    ensure_not_paused();
+
+    let caller = get_immediate_caller();
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    if owner == caller {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[owner, caller]);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let new_spender_allowance = read_allowance_from(owner, caller)
+        .checked_sub(amount)
+        .unwrap_or_revert_with(Cep18Error::InsufficientAllowance);
+
+    burn_balance(owner, amount);
+    write_allowance_to(owner, caller, new_spender_allowance);
+    events::record_event_dictionary(Event::DecreaseAllowance(DecreaseAllowance {
+        owner,
+        spender: caller,
+        allowance: new_spender_allowance,
+        decr_by: amount,
+    }))
+}
+
+fn burn_balance(owner: Key, amount: U256) {
     let new_balance = {
         let balance = read_balance_from(owner);
         balance
@@ -263,6 +349,63 @@ pub extern "C" fn burn() {
     events::record_event_dictionary(Event::Burn(Burn { owner, amount }))
 }
 
+/// Pauser EntryPoint blocking transfers, mints and burns until `unpause` is called.
+#[no_mangle]
+pub extern "C" fn pause() {
+    sec_check(Roles::ADMIN | Roles::PAUSER);
+    if is_paused() {
+        return;
+    }
+    write_paused(true);
+    events::record_event_dictionary(Event::Paused(Paused {
+        pauser: get_immediate_caller(),
+    }));
+}
+
+/// Pauser EntryPoint lifting a `pause`.
+#[no_mangle]
+pub extern "C" fn unpause() {
+    sec_check(Roles::ADMIN | Roles::PAUSER);
+    if !is_paused() {
+        return;
+    }
+    write_paused(false);
+    events::record_event_dictionary(Event::Unpaused(Unpaused {
+        unpauser: get_immediate_caller(),
+    }));
+}
+
+/// Admin EntryPoint freezing an account: transfers from or to it, and approvals by or for it,
+/// revert until `unfreeze` is called.
+#[no_mangle]
+pub extern "C" fn freeze() {
+    sec_check(Roles::ADMIN);
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+    if read_frozen_from(account) {
+        return;
+    }
+    write_frozen_to(account, true);
+    events::record_event_dictionary(Event::Frozen(Frozen {
+        admin: get_immediate_caller(),
+        frozen: account,
+    }));
+}
+
+/// Admin EntryPoint lifting the freeze of an account.
+#[no_mangle]
+pub extern "C" fn unfreeze() {
+    sec_check(Roles::ADMIN);
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+    if !read_frozen_from(account) {
+        return;
+    }
+    write_frozen_to(account, false);
+    events::record_event_dictionary(Event::Unfrozen(Unfrozen {
+        admin: get_immediate_caller(),
+        unfrozen: account,
+    }));
+}
+
 /// Initiates the contracts states. Only used by the installer call,
 /// later calls will cause it to revert.
 #[no_mangle]
@@ -280,6 +423,12 @@ pub extern "C" fn init() {
         .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
     storage::new_dictionary(DICT_BALANCES)
         .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_NONCES)
+        .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_FROZEN)
+        .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_MINTER_ALLOWANCES)
+        .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
     let initial_supply = runtime::get_named_arg(ARG_TOTAL_SUPPLY);
 
     let caller = get_immediate_caller();
@@ -288,49 +437,32 @@ pub extern "C" fn init() {
 
     write_balance_to(initial_balance_holder_key, initial_supply);
 
-    let security_badges_dict = storage::new_dictionary(DICT_SECURITY_BADGES)
-        .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
-    dictionary_put(
-        security_badges_dict,
-        &base64_encode(
-            initial_balance_holder_key
-                .to_bytes()
-                .unwrap_or_revert_with(Cep18Error::FailedToConvertBytes),
-        ),
-        SecurityBadge::Admin,
-    );
+    storage::new_dictionary(DICT_ROLES).unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    write_roles_of(initial_balance_holder_key, Roles::ADMIN);
 
     let admin_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
     let minter_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let pauser_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep18Error::InvalidPauserList);
 
     init_events();
 
+    // Roles add up, so an account listed twice, or the installer listed again, keeps all of them.
     if let Some(minter_list) = minter_list {
         for minter in minter_list {
-            dictionary_put(
-                security_badges_dict,
-                &base64_encode(
-                    minter
-                        .to_bytes()
-                        .unwrap_or_revert_with(Cep18Error::FailedToConvertBytes),
-                ),
-                SecurityBadge::Minter,
-            );
+            write_roles_of(minter, read_roles_of(minter) | Roles::MINTER);
+        }
+    }
+    if let Some(pauser_list) = pauser_list {
+        for pauser in pauser_list {
+            write_roles_of(pauser, read_roles_of(pauser) | Roles::PAUSER);
         }
     }
     if let Some(admin_list) = admin_list {
         for admin in admin_list {
-            dictionary_put(
-                security_badges_dict,
-                &base64_encode(
-                    admin
-                        .to_bytes()
-                        .unwrap_or_revert_with(Cep18Error::FailedToConvertBytes),
-                ),
-                SecurityBadge::Admin,
-            );
+            write_roles_of(admin, read_roles_of(admin) | Roles::ADMIN);
         }
     }
 
@@ -341,23 +473,26 @@ pub extern "C" fn init() {
 }
 
 /// Admin EntryPoint to manipulate the security access granted to users.
-/// One user can only possess one access group badge.
-/// Change strength: None > Admin > Minter
+/// Each listed user gets the single role of one access group badge, replacing all their roles;
+/// use `grant_roles` and `revoke_roles` to give a user several roles.
+/// Change strength: None > Admin > Pauser > Minter
 /// Change strength meaning by example: If user is added to both Minter and Admin they will be an
 /// Admin, also if a user is added to Admin and None then they will be removed from having rights.
-/// Beware: do not remove the last Admin because that will lock out all admin functionality.
+/// Changes removing the last Admin revert, as that would lock out all admin functionality.
 #[no_mangle]
 pub extern "C" fn change_security() {
     if 0 == get_stored_value::<u8>(ARG_ENABLE_MINT_BURN) {
         revert(Cep18Error::MintBurnDisabled);
     }
-    sec_check(vec![SecurityBadge::Admin]);
+    sec_check(Roles::ADMIN);
     let admin_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
     let minter_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
     let none_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(NONE_LIST, Cep18Error::InvalidNoneList);
+    let pauser_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep18Error::InvalidPauserList);
 
     let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
     if let Some(minter_list) = minter_list {
@@ -365,6 +500,11 @@ pub extern "C" fn change_security() {
             badge_map.insert(account_key, SecurityBadge::Minter);
         }
     }
+    if let Some(pauser_list) = pauser_list {
+        for account_key in pauser_list {
+            badge_map.insert(account_key, SecurityBadge::Pauser);
+        }
+    }
     if let Some(admin_list) = admin_list {
         for account_key in admin_list {
             badge_map.insert(account_key, SecurityBadge::Admin);
@@ -386,9 +526,83 @@ pub extern "C" fn change_security() {
     }));
 }
 
+/// Admin EntryPoint adding the roles given as bit flags to the roles of an account.
+#[no_mangle]
+pub extern "C" fn grant_roles() {
+    sec_check(Roles::ADMIN);
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+    let granted = Roles::from_bits(runtime::get_named_arg(ARG_ROLES))
+        .unwrap_or_revert_with(Cep18Error::InvalidRoles);
+    write_roles_of(account, read_roles_of(account) | granted);
+    events::record_event_dictionary(Event::RolesGranted(RolesGranted {
+        admin: get_immediate_caller(),
+        account,
+        granted,
+    }));
+}
+
+/// Admin EntryPoint removing the roles given as bit flags from the roles of an account.
+#[no_mangle]
+pub extern "C" fn revoke_roles() {
+    sec_check(Roles::ADMIN);
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+    let revoked = Roles::from_bits(runtime::get_named_arg(ARG_ROLES))
+        .unwrap_or_revert_with(Cep18Error::InvalidRoles);
+    write_roles_of(account, read_roles_of(account).without(revoked));
+    events::record_event_dictionary(Event::RolesRevoked(RolesRevoked {
+        admin: get_immediate_caller(),
+        account,
+        revoked,
+    }));
+}
+
+/// Admin EntryPoint proposing an account as new Admin, replacing any pending proposal. The
+/// account only becomes an Admin once it calls `accept_admin`, so a mistyped account never does.
+#[no_mangle]
+pub extern "C" fn propose_admin() {
+    sec_check(Roles::ADMIN);
+    let proposer = get_immediate_caller();
+    let new_admin: Key = runtime::get_named_arg(ARG_NEW_ADMIN);
+    write_pending_admin(proposer, new_admin);
+    events::record_event_dictionary(Event::AdminProposed(AdminProposed {
+        proposer,
+        proposed_admin: new_admin,
+    }));
+}
+
+/// EntryPoint with which the account proposed by `propose_admin` becomes an Admin, as long as the
+/// proposing account still is one.
+#[no_mangle]
+pub extern "C" fn accept_admin() {
+    let caller = get_immediate_caller();
+    let (proposer, new_admin) =
+        read_pending_admin().unwrap_or_revert_with(Cep18Error::NotProposedAdmin);
+    if caller != new_admin {
+        revert(Cep18Error::NotProposedAdmin);
+    }
+    if !read_roles_of(proposer).contains(Roles::ADMIN) {
+        revert(Cep18Error::InsufficientRights);
+    }
+    clear_pending_admin();
+    write_roles_of(new_admin, read_roles_of(new_admin) | Roles::ADMIN);
+    events::record_event_dictionary(Event::ChangeSecurity(ChangeSecurity {
+        admin: proposer,
+        sec_change_map: BTreeMap::from([(new_admin, SecurityBadge::Admin)]),
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn roles_of() {
+    let account: Key = runtime::get_named_arg(ARG_ACCOUNT);
+    runtime::ret(
+        CLValue::from_t(read_roles_of(account).bits())
+            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
 #[no_mangle]
 fn change_events_mode() {
-    sec_check(vec![SecurityBadge::Admin]);
+    sec_check(Roles::ADMIN);
     let events_mode: EventsMode = EventsMode::try_from(get_named_arg::<u8>(ARG_EVENTS_MODE))
         .unwrap_or_revert_with(Cep18Error::InvalidEventsMode);
     let old_events_mode: EventsMode = EventsMode::try_from(get_stored_value::<u8>(ARG_EVENTS_MODE))
@@ -405,6 +619,25 @@ fn change_events_mode() {
     }));
 }
 
+/// Brings the state of a token installed by an earlier version up to date, called by `upgrade`
+/// on the new contract version. Tokens in CES mode get the schemas of every current event, so
+/// events added since their install, such as `Paused`, can be recorded. The security badges of
+/// the caller and of the accounts of the optional `badge_holder_list` are converted into roles.
+/// Calling it again changes nothing.
+#[no_mangle]
+pub extern "C" fn migrate() {
+    init_events();
+
+    migrate_badge_of(get_immediate_caller());
+    let badge_holder_list: Option<Vec<Key>> = get_optional_named_arg_with_user_errors(
+        BADGE_HOLDER_LIST,
+        Cep18Error::InvalidBadgeHolderList,
+    );
+    for account in badge_holder_list.unwrap_or_default() {
+        migrate_badge_of(account);
+    }
+}
+
 pub fn upgrade(name: &str) {
     let entry_points = generate_entry_points();
 
@@ -434,6 +667,27 @@ pub fn upgrade(name: &str) {
         ARG_EVENTS_MODE,
         Cep18Error::InvalidEventsMode,
     );
+    let badge_holder_list: Vec<Key> = get_optional_named_arg_with_user_errors(
+        BADGE_HOLDER_LIST,
+        Cep18Error::InvalidBadgeHolderList,
+    )
+    .unwrap_or_default();
+
+    // Tokens installed before the admin count have no count to keep up to date, and their admins
+    // can not be counted on-chain: the count of their admins can be given on upgrade.
+    let mut named_keys = NamedKeys::new();
+    if let Some(admin_count) = get_optional_named_arg_with_user_errors::<u32>(
+        ARG_ADMIN_COUNT,
+        Cep18Error::InvalidAdminCount,
+    ) {
+        if admin_count == 0 {
+            revert(Cep18Error::InvalidAdminCount);
+        }
+        named_keys.insert(
+            ARG_ADMIN_COUNT.to_string(),
+            storage::new_uref(admin_count).into(),
+        );
+    }
 
     let version_value_uref = get_uref_with_user_errors(
         &format!("{PREFIX_CEP18}_{PREFIX_CONTRACT_VERSION}_{name}"),
@@ -456,7 +710,7 @@ pub fn upgrade(name: &str) {
     let (contract_hash, contract_version) = storage::add_contract_version(
         contract_package_hash,
         entry_points,
-        NamedKeys::new(),
+        named_keys,
         message_topics,
     );
 
@@ -481,6 +735,14 @@ pub fn upgrade(name: &str) {
         storage::new_uref(get_contract_version_key(contract_version).to_string()).into(),
     );
 
+    call_contract::<()>(
+        contract_hash,
+        ENTRY_POINT_MIGRATE,
+        runtime_args! {
+            BADGE_HOLDER_LIST => badge_holder_list,
+        },
+    );
+
     if let Some(events_mode_u8) = events_mode {
         call_contract::<()>(
             contract_hash,
@@ -504,6 +766,14 @@ pub fn install_contract(name: &str) {
         get_optional_named_arg_with_user_errors(ADMIN_LIST, Cep18Error::InvalidAdminList);
     let minter_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(MINTER_LIST, Cep18Error::InvalidMinterList);
+    let pauser_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(PAUSER_LIST, Cep18Error::InvalidPauserList);
+
+    let max_supply: Option<U256> =
+        get_optional_named_arg_with_user_errors(ARG_MAX_SUPPLY, Cep18Error::InvalidMaxSupply);
+    if max_supply.is_some_and(|max_supply| total_supply > max_supply) {
+        revert(Cep18Error::InvalidMaxSupply);
+    }
 
     let enable_mint_burn: u8 = get_optional_named_arg_with_user_errors(
         ARG_ENABLE_MINT_BURN,
@@ -527,6 +797,15 @@ pub fn install_contract(name: &str) {
         ARG_ENABLE_MINT_BURN.to_string(),
         storage::new_uref(enable_mint_burn).into(),
     );
+    named_keys.insert(PAUSED.to_string(), storage::new_uref(false).into());
+    // Counted up as `init` writes the Admin role of the installer and of the admin list.
+    named_keys.insert(ARG_ADMIN_COUNT.to_string(), storage::new_uref(0u32).into());
+    if let Some(max_supply) = max_supply {
+        named_keys.insert(
+            ARG_MAX_SUPPLY.to_string(),
+            storage::new_uref(max_supply).into(),
+        );
+    }
 
     let entry_points = generate_entry_points();
 
@@ -571,6 +850,11 @@ pub fn install_contract(name: &str) {
             .insert(MINTER_LIST, minter_list)
             .unwrap_or_revert_with(Cep18Error::FailedToInsertToSecurityList);
     }
+    if let Some(pauser_list) = pauser_list {
+        init_args
+            .insert(PAUSER_LIST, pauser_list)
+            .unwrap_or_revert_with(Cep18Error::FailedToInsertToSecurityList);
+    }
 
     runtime::call_contract::<()>(contract_hash, ENTRY_POINT_INIT, init_args);
 }
@@ -0,0 +1,43 @@
+//! Implementation of per-minter mint quotas.
+use crate::{
+    constants::DICT_MINTER_ALLOWANCES,
+    error::Cep18Error,
+    utils::{base64_encode, get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+use alloc::string::String;
+use casper_contract::{
+    contract_api::{runtime::get_key, storage::new_dictionary},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, U256};
+
+fn make_dictionary_item_key(minter: Key) -> String {
+    base64_encode(
+        minter
+            .to_bytes()
+            .unwrap_or_revert_with(Cep18Error::FailedToConvertBytes),
+    )
+}
+
+/// Writes the amount of tokens a minter can still mint, creating the minter allowances dictionary
+/// on the first quota of a token upgraded from a version without quotas.
+pub fn write_minter_allowance(minter: Key, amount: U256) {
+    if get_key(DICT_MINTER_ALLOWANCES).is_none() {
+        new_dictionary(DICT_MINTER_ALLOWANCES)
+            .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    }
+    set_dictionary_value_for_key(
+        DICT_MINTER_ALLOWANCES,
+        &make_dictionary_item_key(minter),
+        &amount,
+    )
+}
+
+/// Reads the amount of tokens a minter can still mint, `None` for minters never given a quota,
+/// who cannot mint until an admin sets one.
+pub fn read_minter_allowance(minter: Key) -> Option<U256> {
+    if get_key(DICT_MINTER_ALLOWANCES).is_none() {
+        return None;
+    }
+    get_dictionary_value_from_key(DICT_MINTER_ALLOWANCES, &make_dictionary_item_key(minter))
+}
@@ -0,0 +1,77 @@
+//! Implementation of signed permits and their nonces.
+#[cfg(feature = "contract-support")]
+use crate::{
+    constants::DICT_NONCES,
+    error::Cep18Error,
+    utils::{base64_encode, get_dictionary_value_from_key, set_dictionary_value_for_key},
+};
+#[cfg(feature = "contract-support")]
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "contract-support")]
+use casper_contract::{
+    contract_api::{runtime::get_key, storage::new_dictionary},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, U256};
+
+/// Prefix of every permit message, so a permit signature can not be replayed as any other kind
+/// of signed message.
+pub const PERMIT_DOMAIN: &[u8] = b"CEP-18 permit";
+
+/// Builds the message an owner signs off-chain to approve `spender` for `amount`: the
+/// [`PERMIT_DOMAIN`] followed by the serialized token package hash, owner, spender, amount, nonce
+/// and deadline (block time in milliseconds). The package hash binds the permit to one token.
+pub fn permit_message(
+    package_hash: Key,
+    owner: Key,
+    spender: Key,
+    amount: U256,
+    nonce: u64,
+    deadline: u64,
+) -> Vec<u8> {
+    let mut message = PERMIT_DOMAIN.to_vec();
+    for bytes in [
+        package_hash.to_bytes(),
+        owner.to_bytes(),
+        spender.to_bytes(),
+        amount.to_bytes(),
+        nonce.to_bytes(),
+        deadline.to_bytes(),
+    ] {
+        // Serializing keys and numbers can not fail.
+        message.extend(bytes.unwrap_or_default());
+    }
+    message
+}
+
+#[cfg(feature = "contract-support")]
+fn make_dictionary_item_key(owner: Key) -> String {
+    base64_encode(
+        owner
+            .to_bytes()
+            .unwrap_or_revert_with(Cep18Error::FailedToConvertBytes),
+    )
+}
+
+/// Reads the nonce the next permit of `owner` has to carry.
+///
+/// Tokens upgraded from a version without permits have no nonces yet, and every owner starts at
+/// 0.
+#[cfg(feature = "contract-support")]
+pub fn read_nonce_of(owner: Key) -> u64 {
+    if get_key(DICT_NONCES).is_none() {
+        return 0;
+    }
+    get_dictionary_value_from_key(DICT_NONCES, &make_dictionary_item_key(owner)).unwrap_or_default()
+}
+
+/// Writes the nonce the next permit of `owner` has to carry, creating the nonces dictionary on
+/// the first permit of an upgraded token.
+#[cfg(feature = "contract-support")]
+pub fn write_nonce_of(owner: Key, nonce: u64) {
+    if get_key(DICT_NONCES).is_none() {
+        new_dictionary(DICT_NONCES).unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    }
+    set_dictionary_value_for_key(DICT_NONCES, &make_dictionary_item_key(owner), &nonce)
+}
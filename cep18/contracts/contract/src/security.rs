@@ -1,20 +1,30 @@
 #[cfg(feature = "contract-support")]
 use crate::{
-    constants::DICT_SECURITY_BADGES,
+    constants::{DICT_ROLES, DICT_SECURITY_BADGES},
     error::Cep18Error,
-    utils::{base64_encode, get_immediate_caller},
+    utils::{
+        base64_encode, get_dictionary_value_from_key, get_immediate_caller, read_admin_count,
+        set_dictionary_value_for_key, write_admin_count,
+    },
 };
 #[cfg(feature = "contract-support")]
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, string::String};
 use alloc::{vec, vec::Vec};
 #[cfg(feature = "contract-support")]
-use casper_contract::{contract_api::runtime::revert, unwrap_or_revert::UnwrapOrRevert};
+use casper_contract::{
+    contract_api::{
+        runtime::{get_key, revert},
+        storage::new_dictionary,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
 #[cfg(feature = "contract-support")]
 use casper_types::Key;
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
     CLTyped,
 };
+use core::ops::BitOr;
 use serde::{Deserialize, Serialize};
 
 #[repr(u8)]
@@ -23,6 +33,7 @@ pub enum SecurityBadge {
     Admin = 0,
     Minter = 1,
     None = 2,
+    Pauser = 3,
 }
 
 impl CLTyped for SecurityBadge {
@@ -43,43 +54,216 @@ impl ToBytes for SecurityBadge {
 
 impl FromBytes for SecurityBadge {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        Ok((
-            match bytes[0] {
-                0 => SecurityBadge::Admin,
-                1 => SecurityBadge::Minter,
-                2 => SecurityBadge::None,
-                _ => return Err(bytesrepr::Error::LeftOverBytes),
-            },
-            &[],
-        ))
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let badge = match tag {
+            0 => SecurityBadge::Admin,
+            1 => SecurityBadge::Minter,
+            2 => SecurityBadge::None,
+            3 => SecurityBadge::Pauser,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((badge, remainder))
+    }
+}
+
+/// The set of roles held by one account, stored as bit flags so an account can hold several of
+/// them at once.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct Roles(u8);
+
+impl Roles {
+    pub const NONE: Roles = Roles(0);
+    pub const ADMIN: Roles = Roles(1);
+    pub const MINTER: Roles = Roles(1 << 1);
+    pub const BURNER: Roles = Roles(1 << 2);
+    pub const PAUSER: Roles = Roles(1 << 3);
+    pub const ALL: Roles = Roles(0b1111);
+
+    /// Returns the roles of the given bit flags, or `None` if a flag matches no role.
+    pub fn from_bits(bits: u8) -> Option<Roles> {
+        if bits & !Roles::ALL.0 == 0 {
+            Some(Roles(bits))
+        } else {
+            None
+        }
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if every role of `other` is held.
+    pub fn contains(self, other: Roles) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any role of `other` is held.
+    pub fn intersects(self, other: Roles) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns the held roles without the roles of `other`.
+    pub fn without(self, other: Roles) -> Roles {
+        Roles(self.0 & !other.0)
+    }
+}
+
+impl BitOr for Roles {
+    type Output = Roles;
+
+    fn bitor(self, other: Roles) -> Roles {
+        Roles(self.0 | other.0)
+    }
+}
+
+impl From<SecurityBadge> for Roles {
+    fn from(badge: SecurityBadge) -> Self {
+        match badge {
+            SecurityBadge::Admin => Roles::ADMIN,
+            SecurityBadge::Minter => Roles::MINTER,
+            SecurityBadge::None => Roles::NONE,
+            SecurityBadge::Pauser => Roles::PAUSER,
+        }
+    }
+}
+
+impl CLTyped for Roles {
+    fn cl_type() -> casper_types::CLType {
+        casper_types::CLType::U8
+    }
+}
+
+impl ToBytes for Roles {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        Ok(vec![self.0])
+    }
+
+    fn serialized_length(&self) -> usize {
+        1
+    }
+}
+
+impl FromBytes for Roles {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (bits, remainder) = u8::from_bytes(bytes)?;
+        let roles = Roles::from_bits(bits).ok_or(bytesrepr::Error::Formatting)?;
+        Ok((roles, remainder))
+    }
+}
+
+#[cfg(feature = "contract-support")]
+fn make_dictionary_item_key(account: Key) -> String {
+    base64_encode(
+        account
+            .to_bytes()
+            .unwrap_or_revert_with(Cep18Error::FailedToConvertBytes),
+    )
+}
+
+/// Reads the roles of an account.
+#[cfg(feature = "contract-support")]
+pub fn read_roles_of(account: Key) -> Roles {
+    if get_key(DICT_ROLES).is_none() {
+        return Roles::NONE;
     }
+    get_dictionary_value_from_key::<Roles>(DICT_ROLES, &make_dictionary_item_key(account))
+        .unwrap_or(Roles::NONE)
 }
 
+/// Converts the security badge of an account, left by a version with single security badges,
+/// into the matching role. Accounts that already have roles, or never had a badge, are left
+/// as they are, so migrating an account twice changes nothing.
+///
+/// Dictionaries can not be enumerated on-chain, so `migrate` converts the badges of the accounts
+/// it is given.
 #[cfg(feature = "contract-support")]
-pub fn sec_check(allowed_badge_list: Vec<SecurityBadge>) {
-    use crate::utils::get_dictionary_value_from_key;
+pub fn migrate_badge_of(account: Key) {
+    if get_key(DICT_SECURITY_BADGES).is_none() {
+        return;
+    }
+    let item_key = make_dictionary_item_key(account);
+    if get_key(DICT_ROLES).is_some()
+        && get_dictionary_value_from_key::<Roles>(DICT_ROLES, &item_key).is_some()
+    {
+        return;
+    }
+    if let Some(badge) =
+        get_dictionary_value_from_key::<SecurityBadge>(DICT_SECURITY_BADGES, &item_key)
+    {
+        if get_key(DICT_ROLES).is_none() {
+            new_dictionary(DICT_ROLES).unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+        }
+        set_dictionary_value_for_key(DICT_ROLES, &item_key, &Roles::from(badge))
+    }
+}
 
-    let caller = get_immediate_caller();
-    let caller = caller.to_bytes().unwrap_or_revert();
+/// Writes the roles of an account, creating the roles dictionary on the first write of a token
+/// upgraded from a version with single security badges.
+///
+/// Keeps the admin count up to date, and reverts with [`Cep18Error::CannotRemoveLastAdmin`]
+/// instead of removing the Admin role from the last account holding it. Tokens upgraded without
+/// an admin count can not tell the last Admin apart, so removing any Admin role reverts with
+/// [`Cep18Error::AdminCountUnknown`] until the count is given on upgrade.
+#[cfg(feature = "contract-support")]
+pub fn write_roles_of(account: Key, roles: Roles) {
+    write_roles(&[(account, roles)]);
+}
 
-    let badge: SecurityBadge =
-        get_dictionary_value_from_key(DICT_SECURITY_BADGES, &base64_encode(caller))
-            .unwrap_or_revert_with(Cep18Error::InsufficientRights);
+/// Writes the roles of several accounts like [`write_roles_of`], but checks the admin count once
+/// against the net change of the whole batch, so one call can promote a new Admin and demote an
+/// old one in any order.
+#[cfg(feature = "contract-support")]
+fn write_roles(changes: &[(Key, Roles)]) {
+    if get_key(DICT_ROLES).is_none() {
+        new_dictionary(DICT_ROLES).unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    }
+    let (mut added, mut removed) = (0u32, 0u32);
+    for &(account, roles) in changes {
+        match (
+            read_roles_of(account).contains(Roles::ADMIN),
+            roles.contains(Roles::ADMIN),
+        ) {
+            (false, true) => added += 1,
+            (true, false) => removed += 1,
+            _ => {}
+        }
+        set_dictionary_value_for_key(DICT_ROLES, &make_dictionary_item_key(account), &roles)
+    }
+    if added == removed {
+        return;
+    }
+    match read_admin_count() {
+        Some(admin_count) => {
+            let admin_count = admin_count
+                .checked_add(added)
+                .unwrap_or_revert_with(Cep18Error::Overflow)
+                .saturating_sub(removed);
+            if admin_count == 0 {
+                revert(Cep18Error::CannotRemoveLastAdmin);
+            }
+            write_admin_count(admin_count);
+        }
+        None if removed > added => revert(Cep18Error::AdminCountUnknown),
+        None => {}
+    }
+}
 
-    if !allowed_badge_list.contains(&badge) {
+/// Reverts with [`Cep18Error::InsufficientRights`] unless the caller holds any of the `allowed`
+/// roles.
+#[cfg(feature = "contract-support")]
+pub fn sec_check(allowed: Roles) {
+    if !read_roles_of(get_immediate_caller()).intersects(allowed) {
         revert(Cep18Error::InsufficientRights)
     }
 }
 
+/// Replaces the roles of every account of `badge_map` with the role of its badge, checking the
+/// admin count once for the whole map.
 #[cfg(feature = "contract-support")]
 pub fn change_sec_badge(badge_map: &BTreeMap<Key, SecurityBadge>) {
-    use crate::utils::set_dictionary_value_for_key;
-
-    for (&user, &badge) in badge_map {
-        set_dictionary_value_for_key(
-            DICT_SECURITY_BADGES,
-            &base64_encode(user.to_bytes().unwrap_or_revert()),
-            &badge,
-        )
-    }
+    let changes: Vec<(Key, Roles)> = badge_map
+        .iter()
+        .map(|(&user, &badge)| (user, Roles::from(badge)))
+        .collect();
+    write_roles(&changes);
 }
@@ -0,0 +1,179 @@
+//! Bodies of the standard CEP-18 entry points, shared by the token and by contracts built on the
+//! `cep18` crate, such as WCSPR. Each contract exports them under its own `#[no_mangle]` names.
+//!
+//! Pause and freeze checks pass on contracts that have neither.
+use crate::{
+    allowances::{read_allowance_from, write_allowance_to},
+    balances::{read_balance_from, transfer_balance},
+    constants::{
+        ARG_ADDRESS, ARG_AMOUNT, ARG_DECIMALS, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_SPENDER,
+        ARG_SYMBOL, ARG_TOTAL_SUPPLY,
+    },
+    error::Cep18Error,
+    events::{
+        self, DecreaseAllowance, Event, IncreaseAllowance, SetAllowance, Transfer, TransferFrom,
+    },
+    frozen::ensure_not_frozen,
+    utils::{ensure_not_paused, get_immediate_caller, get_stored_value},
+};
+use alloc::string::String;
+use casper_contract::{
+    contract_api::runtime::{self, revert},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{CLValue, Key, U256};
+
+/// Returns the name of the token.
+pub fn name() {
+    runtime::ret(
+        CLValue::from_t(get_stored_value::<String>(ARG_NAME))
+            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
+/// Returns the symbol of the token.
+pub fn symbol() {
+    runtime::ret(
+        CLValue::from_t(get_stored_value::<String>(ARG_SYMBOL))
+            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
+/// Returns the decimals of the token.
+pub fn decimals() {
+    runtime::ret(
+        CLValue::from_t(get_stored_value::<u8>(ARG_DECIMALS))
+            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
+/// Returns the total supply of the token.
+pub fn total_supply() {
+    runtime::ret(
+        CLValue::from_t(get_stored_value::<U256>(ARG_TOTAL_SUPPLY))
+            .unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
+/// Returns the balance of `address`.
+pub fn balance_of() {
+    let address: Key = runtime::get_named_arg(ARG_ADDRESS);
+    let balance = read_balance_from(address);
+    runtime::ret(
+        CLValue::from_t(balance).unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
+/// Returns the allowance `owner` gave `spender`.
+pub fn allowance() {
+    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    let val: U256 = read_allowance_from(owner, spender);
+    runtime::ret(
+        CLValue::from_t(val).unwrap_or_revert_with(Cep18Error::FailedToReturnEntryPointResult),
+    );
+}
+
+/// Sets the allowance of `spender` over the caller's tokens to `amount`.
+pub fn approve() {
+    let caller = get_immediate_caller();
+    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
+    if spender == caller {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[caller, spender]);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    write_allowance_to(caller, spender, amount);
+    events::record_event_dictionary(Event::SetAllowance(SetAllowance {
+        owner: caller,
+        spender,
+        allowance: amount,
+    }))
+}
+
+/// Decreases the allowance of `spender` over the caller's tokens by `amount`, down to zero.
+pub fn decrease_allowance() {
+    let caller = get_immediate_caller();
+    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
+    if spender == caller {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    let current_allowance = read_allowance_from(caller, spender);
+    let new_allowance = current_allowance.saturating_sub(amount);
+    write_allowance_to(caller, spender, new_allowance);
+    events::record_event_dictionary(Event::DecreaseAllowance(DecreaseAllowance {
+        owner: caller,
+        spender,
+        decr_by: amount,
+        allowance: new_allowance,
+    }))
+}
+
+/// Increases the allowance of `spender` over the caller's tokens by `amount`.
+pub fn increase_allowance() {
+    let caller = get_immediate_caller();
+    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
+    if spender == caller {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[caller, spender]);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    let current_allowance = read_allowance_from(caller, spender);
+    let new_allowance = current_allowance.saturating_add(amount);
+    write_allowance_to(caller, spender, new_allowance);
+    events::record_event_dictionary(Event::IncreaseAllowance(IncreaseAllowance {
+        owner: caller,
+        spender,
+        allowance: new_allowance,
+        inc_by: amount,
+    }))
+}
+
+/// Moves `amount` tokens from the caller to `recipient`.
+pub fn transfer() {
+    ensure_not_paused();
+    let caller = get_immediate_caller();
+    let recipient: Key = runtime::get_named_arg(ARG_RECIPIENT);
+    if caller == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[caller, recipient]);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    transfer_balance(caller, recipient, amount).unwrap_or_revert();
+    events::record_event_dictionary(Event::Transfer(Transfer {
+        sender: caller,
+        recipient,
+        amount,
+    }))
+}
+
+/// Moves `amount` tokens from `owner` to `recipient`, spending the allowance `owner` gave the caller.
+pub fn transfer_from() {
+    ensure_not_paused();
+    let caller = get_immediate_caller();
+    let recipient: Key = runtime::get_named_arg(ARG_RECIPIENT);
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    if owner == recipient {
+        revert(Cep18Error::CannotTargetSelfUser);
+    }
+    ensure_not_frozen(&[owner, recipient, caller]);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    if amount.is_zero() {
+        return;
+    }
+
+    let spender_allowance: U256 = read_allowance_from(owner, caller);
+    let new_spender_allowance = spender_allowance
+        .checked_sub(amount)
+        .unwrap_or_revert_with(Cep18Error::InsufficientAllowance);
+
+    transfer_balance(owner, recipient, amount).unwrap_or_revert();
+    write_allowance_to(owner, caller, new_spender_allowance);
+    events::record_event_dictionary(Event::TransferFrom(TransferFrom {
+        spender: caller,
+        owner,
+        recipient,
+        amount,
+    }))
+}
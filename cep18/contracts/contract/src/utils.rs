@@ -1,4 +1,7 @@
-use crate::{constants::ARG_TOTAL_SUPPLY, error::Cep18Error};
+use crate::{
+    constants::{ARG_ADMIN_COUNT, ARG_MAX_SUPPLY, ARG_TOTAL_SUPPLY, PAUSED, PENDING_ADMIN},
+    error::Cep18Error,
+};
 use alloc::{string::String, vec, vec::Vec};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use casper_contract::{
@@ -6,9 +9,9 @@ use casper_contract::{
         self,
         runtime::{
             blake2b, get_immediate_caller as casper_get_immediate_caller, get_key,
-            get_protocol_version, revert,
+            get_protocol_version, put_key, remove_key, revert,
         },
-        storage::{dictionary_get, dictionary_put, read, write},
+        storage::{dictionary_get, dictionary_put, new_uref, read, write},
     },
     ext_ffi::{casper_get_key, casper_get_named_arg, casper_get_named_arg_size},
     unwrap_or_revert::UnwrapOrRevert,
@@ -237,6 +240,59 @@ pub fn base64_encode(data: Vec<u8>) -> String {
     STANDARD.encode(data)
 }
 
+/// Reads the cap on the total supply, `None` for tokens installed without one.
+pub fn read_max_supply() -> Option<U256> {
+    get_key(ARG_MAX_SUPPLY).map(|_| get_stored_value(ARG_MAX_SUPPLY))
+}
+
+/// Whether transfers, mints and burns are paused. Tokens upgraded from a version without pausing
+/// have no `paused` key until they are first paused.
+pub fn is_paused() -> bool {
+    get_key(PAUSED).is_some() && get_stored_value::<bool>(PAUSED)
+}
+
+/// Reverts with [`Cep18Error::ContractPaused`] while the contract is paused.
+pub fn ensure_not_paused() {
+    if is_paused() {
+        revert(Cep18Error::ContractPaused);
+    }
+}
+
+pub fn write_paused(paused: bool) {
+    match get_key(PAUSED) {
+        Some(_) => write(get_uref(PAUSED), paused),
+        None => put_key(PAUSED, new_uref(paused).into()),
+    }
+}
+
+/// Reads the number of accounts holding the Admin role, `None` for tokens upgraded from a version
+/// without the count and not given one on upgrade.
+pub fn read_admin_count() -> Option<u32> {
+    get_key(ARG_ADMIN_COUNT).map(|_| get_stored_value(ARG_ADMIN_COUNT))
+}
+
+pub fn write_admin_count(admin_count: u32) {
+    write(get_uref(ARG_ADMIN_COUNT), admin_count);
+}
+
+/// Reads the pending admin handover as the proposing admin and the proposed admin, `None` when no
+/// handover is pending.
+pub fn read_pending_admin() -> Option<(Key, Key)> {
+    get_key(PENDING_ADMIN).map(|_| get_stored_value(PENDING_ADMIN))
+}
+
+/// Records a pending admin handover, replacing any previous one.
+pub fn write_pending_admin(proposer: Key, new_admin: Key) {
+    match get_key(PENDING_ADMIN) {
+        Some(_) => write(get_uref(PENDING_ADMIN), (proposer, new_admin)),
+        None => put_key(PENDING_ADMIN, new_uref((proposer, new_admin)).into()),
+    }
+}
+
+pub fn clear_pending_admin() {
+    remove_key(PENDING_ADMIN);
+}
+
 // Writes a total supply to a specific [`URef`].
 pub fn write_total_supply_to(value: U256) {
     get_uref(ARG_TOTAL_SUPPLY);
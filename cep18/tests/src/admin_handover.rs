@@ -0,0 +1,171 @@
+use crate::utility::{
+    constants::{TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY},
+    installer_request_builders::{get_test_account, setup_with_args, TestContext},
+};
+use casper_engine_test_support::{
+    ExecuteRequest, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{
+    account::AccountHash, runtime_args, AddressableEntityHash, ApiError, EntityAddr, Key,
+    RuntimeArgs, U256,
+};
+use cep18::{
+    constants::{
+        ADMIN_LIST, ARG_ACCOUNT, ARG_ADMIN_COUNT, ARG_DECIMALS, ARG_ENABLE_MINT_BURN, ARG_NAME,
+        ARG_NEW_ADMIN, ARG_ROLES, ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENTRY_POINT_ACCEPT_ADMIN,
+        ENTRY_POINT_FREEZE, ENTRY_POINT_PROPOSE_ADMIN, ENTRY_POINT_REVOKE_ROLES, PENDING_ADMIN,
+    },
+    error::Cep18Error,
+    security::Roles,
+};
+
+fn setup_with_admins(admin_list: Vec<Key>) -> (LmdbWasmTestBuilder, TestContext) {
+    setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_MINT_BURN => true,
+        ADMIN_LIST => admin_list,
+    })
+}
+
+fn make_propose_admin_request(
+    cep18_contract_hash: AddressableEntityHash,
+    caller: AccountHash,
+    new_admin: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep18_contract_hash,
+        ENTRY_POINT_PROPOSE_ADMIN,
+        runtime_args! {
+            ARG_NEW_ADMIN => new_admin,
+        },
+    )
+    .build()
+}
+
+fn make_accept_admin_request(
+    cep18_contract_hash: AddressableEntityHash,
+    caller: AccountHash,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep18_contract_hash,
+        ENTRY_POINT_ACCEPT_ADMIN,
+        RuntimeArgs::default(),
+    )
+    .build()
+}
+
+fn assert_cep18_error(builder: &LmdbWasmTestBuilder, expected: Cep18Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected as u16),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn should_hand_admin_over_in_two_steps() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_with_admins(vec![]);
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+
+    let propose_request = make_propose_admin_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        account_user_1_key,
+    );
+    builder.exec(propose_request).expect_success().commit();
+
+    // Proposed accounts are no Admins until they accept.
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_FREEZE,
+        runtime_args! {
+            ARG_ACCOUNT => owner,
+        },
+    )
+    .build();
+    builder.exec(freeze_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InsufficientRights);
+
+    let accept_request = make_accept_admin_request(cep18_contract_hash, *DEFAULT_ACCOUNT_ADDR);
+    builder.exec(accept_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::NotProposedAdmin);
+
+    let accept_request =
+        make_accept_admin_request(cep18_contract_hash, account_user_1_account_hash);
+    builder.exec(accept_request).expect_success().commit();
+
+    let contract_addr = EntityAddr::new_smart_contract(cep18_contract_hash.value());
+    let admin_count: u32 = builder.get_value(contract_addr, ARG_ADMIN_COUNT);
+    assert_eq!(admin_count, 2);
+    assert!(builder
+        .get_named_keys(contract_addr)
+        .get(PENDING_ADMIN)
+        .is_none());
+
+    // The previous admin can now step down.
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_REVOKE_ROLES,
+        runtime_args! {
+            ARG_ACCOUNT => owner,
+            ARG_ROLES => Roles::ADMIN.bits(),
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_success().commit();
+
+    let accept_request =
+        make_accept_admin_request(cep18_contract_hash, account_user_1_account_hash);
+    builder.exec(accept_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::NotProposedAdmin);
+}
+
+#[test]
+fn should_not_accept_proposal_of_removed_admin() {
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_with_admins(vec![account_user_1_key]);
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let propose_request =
+        make_propose_admin_request(cep18_contract_hash, account_user_1_account_hash, owner);
+    builder.exec(propose_request).expect_success().commit();
+
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_REVOKE_ROLES,
+        runtime_args! {
+            ARG_ACCOUNT => account_user_1_key,
+            ARG_ROLES => Roles::ADMIN.bits(),
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_success().commit();
+
+    let accept_request = make_accept_admin_request(cep18_contract_hash, *DEFAULT_ACCOUNT_ADDR);
+    builder.exec(accept_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InsufficientRights);
+}
@@ -0,0 +1,127 @@
+use crate::utility::{
+    constants::{AMOUNT_ALLOWANCE_1, AMOUNT_TRANSFER_1},
+    installer_request_builders::{
+        cep18_check_balance_of, get_test_account, make_cep18_approve_request,
+        make_cep18_transfer_request, setup, TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequest, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{
+    account::AccountHash, runtime_args, AddressableEntityHash, ApiError, Key, U256,
+};
+use cep18::{
+    constants::{ARG_ACCOUNT, ENTRY_POINT_FREEZE, ENTRY_POINT_UNFREEZE},
+    error::Cep18Error,
+};
+
+fn make_freeze_request(
+    caller: AccountHash,
+    cep18_contract_hash: AddressableEntityHash,
+    entry_point: &str,
+    account: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep18_contract_hash,
+        entry_point,
+        runtime_args! {
+            ARG_ACCOUNT => account,
+        },
+    )
+    .build()
+}
+
+fn assert_cep18_error(builder: &LmdbWasmTestBuilder, expected: Cep18Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected as u16),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn should_block_transfers_and_approvals_of_frozen_account() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
+    let transfer_amount = U256::from(AMOUNT_TRANSFER_1);
+
+    let freeze_request = make_freeze_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_FREEZE,
+        account_user_1_key,
+    );
+    builder.exec(freeze_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_contract_hash,
+        account_user_1_key,
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::AccountFrozen);
+
+    let approve_request = make_cep18_approve_request(
+        owner,
+        &cep18_contract_hash,
+        account_user_1_key,
+        U256::from(AMOUNT_ALLOWANCE_1),
+    );
+    builder.exec(approve_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::AccountFrozen);
+
+    let unfreeze_request = make_freeze_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_UNFREEZE,
+        account_user_1_key,
+    );
+    builder.exec(unfreeze_request).expect_success().commit();
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_contract_hash,
+        account_user_1_key,
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_1_key),
+        transfer_amount
+    );
+}
+
+#[test]
+fn should_only_let_admins_freeze() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let (_, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+
+    let freeze_request = make_freeze_request(
+        account_user_1_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_FREEZE,
+        owner,
+    );
+    builder.exec(freeze_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InsufficientRights);
+}
@@ -6,9 +6,10 @@ use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecE
 use casper_types::{runtime_args, system::mint::TOTAL_SUPPLY_KEY, ApiError, EntityAddr, Key, U256};
 use cep18::{
     constants::{
-        ARG_DECIMALS, ARG_ENABLE_MINT_BURN, ARG_EVENTS_MODE, ARG_NAME, ARG_SYMBOL,
+        ARG_DECIMALS, ARG_ENABLE_MINT_BURN, ARG_EVENTS_MODE, ARG_MAX_SUPPLY, ARG_NAME, ARG_SYMBOL,
         ARG_TOTAL_SUPPLY, DICT_ALLOWANCES, DICT_BALANCES,
     },
+    error::Cep18Error,
     modalities::EventsMode,
 };
 
@@ -107,3 +108,35 @@ fn should_fail_with_left_over_bytes_converted_into_60006() {
         "{error:?}",
     );
 }
+
+#[test]
+fn should_not_install_with_max_supply_below_total_supply() {
+    let mut builder = LmdbWasmTestBuilder::default();
+    builder
+        .run_genesis(create_run_genesis_request(DEFAULT_ACCOUNTS.to_vec()))
+        .commit();
+
+    let install_request_1 = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_SYMBOL => TOKEN_SYMBOL,
+            ARG_DECIMALS => TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+            ARG_MAX_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY - 1),
+        },
+    )
+    .build();
+
+    builder.exec(install_request_1).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::InvalidMaxSupply as u16
+        ),
+        "{error:?}",
+    );
+}
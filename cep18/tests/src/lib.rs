@@ -1,14 +1,24 @@
 #[cfg(test)]
+mod admin_handover;
+#[cfg(test)]
 mod allowance;
 #[cfg(test)]
 mod events;
 #[cfg(test)]
+mod freeze;
+#[cfg(test)]
 mod install;
 #[cfg(test)]
 mod migration;
 #[cfg(test)]
 mod mint_and_burn;
 #[cfg(test)]
+mod pause;
+#[cfg(test)]
+mod permit;
+#[cfg(test)]
+mod roles;
+#[cfg(test)]
 mod transfer;
 #[cfg(test)]
 mod upgrade;
@@ -1,13 +1,23 @@
 use casper_engine_test_support::{
     ExecuteRequestBuilder, LmdbWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_ACCOUNT_ADDR,
 };
+use casper_event_standard::{Schemas, EVENTS_LENGTH, EVENTS_SCHEMA};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
 use casper_fixtures::LmdbFixtureState;
 use casper_types::{
-    runtime_args, AddressableEntityHash, EntityAddr, EraId, Key, ProtocolVersion, RuntimeArgs, U256,
+    runtime_args, AddressableEntityHash, ApiError, EntityAddr, EraId, Key, ProtocolVersion,
+    RuntimeArgs, U256,
 };
 use cep18::{
-    constants::{ARG_AMOUNT, ARG_EVENTS, ARG_EVENTS_MODE, ARG_NAME, ARG_OWNER, ENTRY_POINT_MINT},
+    constants::{
+        ARG_ACCOUNT, ARG_ADMIN_COUNT, ARG_AMOUNT, ARG_EVENTS, ARG_EVENTS_MODE, ARG_MAX_SUPPLY,
+        ARG_NAME, ARG_OWNER, ARG_ROLES, BADGE_HOLDER_LIST, DICT_ROLES, ENTRY_POINT_GRANT_ROLES,
+        ENTRY_POINT_MINT, ENTRY_POINT_PAUSE, ENTRY_POINT_REVOKE_ROLES,
+    },
+    error::Cep18Error,
+    events::Paused,
     modalities::EventsMode,
+    security::Roles,
 };
 
 use crate::utility::{
@@ -17,7 +27,7 @@ use crate::utility::{
     },
     installer_request_builders::{cep18_check_balance_of, get_test_account},
     message_handlers::{message_summary, message_topic},
-    support::query_stored_value,
+    support::{get_event, query_stored_value},
 };
 
 pub fn upgrade_v1_5_6_fixture_to_v2_0_0_ee(
@@ -128,6 +138,7 @@ fn should_migrate_1_5_6_to_2_0_0() {
         runtime_args! {
             ARG_NAME => TOKEN_NAME,
             ARG_EVENTS_MODE => EventsMode::Native as u8,
+            ARG_ADMIN_COUNT => 1u32,
         },
     )
     .build();
@@ -162,6 +173,17 @@ fn should_migrate_1_5_6_to_2_0_0() {
     let cep18_contract_hash = get_contract_hash_v2_binary(&builder);
     let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
 
+    // tokens installed before supply caps existed stay uncapped
+    let named_keys = builder.get_named_keys(EntityAddr::SmartContract(cep18_contract_hash.value()));
+    assert!(named_keys.get(ARG_MAX_SUPPLY).is_none());
+
+    // the admin count of tokens installed before it existed is given on upgrade
+    let admin_count: u32 = builder.get_value(
+        EntityAddr::SmartContract(cep18_contract_hash.value()),
+        ARG_ADMIN_COUNT,
+    );
+    assert_eq!(admin_count, 1);
+
     // mint some new tokens in cep-18
     let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
         *DEFAULT_ACCOUNT_ADDR,
@@ -186,6 +208,27 @@ fn should_migrate_1_5_6_to_2_0_0() {
         cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_1_key),
         U256::from(AMOUNT_1),
     );
+
+    // migrated tokens start unpaused and can be paused
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_PAUSE,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.exec(pause_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {ARG_OWNER => account_user_1_key, ARG_AMOUNT => U256::from(AMOUNT_1)},
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
 }
 
 #[test]
@@ -237,3 +280,295 @@ fn should_have_native_events() {
 
     message_summary(&builder, &cep18_token, message_topic_hash, 0, None).unwrap();
 }
+
+#[test]
+fn should_pause_after_upgrade_in_ces_mode() {
+    let (mut builder, lmdb_fixture_state, _temp_dir) =
+        casper_fixtures::builder_from_global_state_fixture("cep18-1.5.6-minted");
+
+    upgrade_v1_5_6_fixture_to_v2_0_0_ee(&mut builder, &lmdb_fixture_state);
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_EVENTS_MODE => EventsMode::CES as u8,
+            ARG_ADMIN_COUNT => 1u32,
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let cep18_token = get_contract_hash_v2_binary(&builder);
+    let entity_addr = EntityAddr::SmartContract(cep18_token.value());
+
+    // the upgrade registers the schemas of events added since the token was installed
+    let schemas: Schemas = builder.get_value(entity_addr, EVENTS_SCHEMA);
+    assert!(schemas.0.contains_key("Paused"), "{schemas:?}");
+    assert!(schemas.0.contains_key("Unpaused"), "{schemas:?}");
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        ENTRY_POINT_PAUSE,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.exec(pause_request).expect_success().commit();
+
+    let events_length: u32 = builder.get_value(entity_addr, EVENTS_LENGTH);
+    let paused: Paused = get_event(&mut builder, &cep18_token, events_length - 1);
+    assert_eq!(
+        paused,
+        Paused {
+            pauser: Key::from(*DEFAULT_ACCOUNT_ADDR)
+        }
+    );
+
+    let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_token,
+        ENTRY_POINT_MINT,
+        runtime_args! {ARG_OWNER => account_user_1_key, ARG_AMOUNT => U256::from(AMOUNT_1)},
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+}
+
+#[test]
+fn should_read_security_badges_of_migrated_tokens_as_roles() {
+    let (mut builder, lmdb_fixture_state, _temp_dir) =
+        casper_fixtures::builder_from_global_state_fixture("cep18-1.5.6-minted");
+
+    upgrade_v1_5_6_fixture_to_v2_0_0_ee(&mut builder, &lmdb_fixture_state);
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_ADMIN_COUNT => 1u32,
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let cep18_token = get_contract_hash_v2_binary(&builder);
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+
+    // the upgrade converts the installer's Admin badge into the Admin role, which it keeps when
+    // roles are added
+    for (account, roles) in [
+        (Key::from(*DEFAULT_ACCOUNT_ADDR), Roles::MINTER),
+        (account_user_1_key, Roles::PAUSER),
+    ] {
+        let grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            ENTRY_POINT_GRANT_ROLES,
+            runtime_args! {ARG_ACCOUNT => account, ARG_ROLES => roles.bits()},
+        )
+        .build();
+
+        builder.exec(grant_request).expect_success().commit();
+    }
+
+    let admin_count: u32 = builder.get_value(
+        EntityAddr::SmartContract(cep18_token.value()),
+        ARG_ADMIN_COUNT,
+    );
+    assert_eq!(admin_count, 1);
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1_account_hash,
+        cep18_token,
+        ENTRY_POINT_PAUSE,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.exec(pause_request).expect_success().commit();
+}
+
+#[test]
+fn should_keep_rights_of_pre_upgrade_admins() {
+    let (mut builder, lmdb_fixture_state, _temp_dir) =
+        casper_fixtures::builder_from_global_state_fixture("cep18-1.5.6-minted");
+
+    upgrade_v1_5_6_fixture_to_v2_0_0_ee(&mut builder, &lmdb_fixture_state);
+
+    // accounts listed without a badge are skipped
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_ADMIN_COUNT => 1u32,
+            BADGE_HOLDER_LIST => vec![account_user_1_key],
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    // the badge is converted by the upgrade itself, before any role is written
+    let cep18_token = get_contract_hash_v2_binary(&builder);
+    let entity_addr = EntityAddr::SmartContract(cep18_token.value());
+    assert!(builder
+        .get_named_keys(entity_addr)
+        .get(DICT_ROLES)
+        .is_some());
+
+    let make_request = |caller, entry_point: &str, account: Key, roles: Roles| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            caller,
+            cep18_token,
+            entry_point,
+            runtime_args! {ARG_ACCOUNT => account, ARG_ROLES => roles.bits()},
+        )
+        .build()
+    };
+
+    builder
+        .exec(make_request(
+            account_user_1_account_hash,
+            ENTRY_POINT_GRANT_ROLES,
+            account_user_1_key,
+            Roles::PAUSER,
+        ))
+        .expect_failure();
+
+    builder
+        .exec(make_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            ENTRY_POINT_GRANT_ROLES,
+            account_user_1_key,
+            Roles::PAUSER,
+        ))
+        .expect_success()
+        .commit();
+
+    // the pre-upgrade admin is the counted, and only, Admin
+    builder
+        .exec(make_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            ENTRY_POINT_REVOKE_ROLES,
+            Key::from(*DEFAULT_ACCOUNT_ADDR),
+            Roles::ADMIN,
+        ))
+        .expect_failure();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::CannotRemoveLastAdmin as u16),
+        "{error:?}"
+    );
+    let admin_count: u32 = builder.get_value(entity_addr, ARG_ADMIN_COUNT);
+    assert_eq!(admin_count, 1);
+}
+
+#[test]
+fn should_not_remove_admins_of_migrated_tokens_until_counted() {
+    let (mut builder, lmdb_fixture_state, _temp_dir) =
+        casper_fixtures::builder_from_global_state_fixture("cep18-1.5.6-minted");
+
+    upgrade_v1_5_6_fixture_to_v2_0_0_ee(&mut builder, &lmdb_fixture_state);
+
+    // upgrade without giving the admin count
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let cep18_token = get_contract_hash_v2_binary(&builder);
+    let entity_addr = EntityAddr::SmartContract(cep18_token.value());
+    assert!(builder
+        .get_named_keys(entity_addr)
+        .get(ARG_ADMIN_COUNT)
+        .is_none());
+
+    let owner = Key::from(*DEFAULT_ACCOUNT_ADDR);
+    let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
+    let make_roles_request = |entry_point: &str, account: Key| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            entry_point,
+            runtime_args! {ARG_ACCOUNT => account, ARG_ROLES => Roles::ADMIN.bits()},
+        )
+        .build()
+    };
+    let assert_cep18_error = |builder: &LmdbWasmTestBuilder, expected: Cep18Error| {
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected as u16),
+            "{error:?}"
+        );
+    };
+
+    // admins can be added, but with an unknown count none can be removed
+    builder
+        .exec(make_roles_request(
+            ENTRY_POINT_GRANT_ROLES,
+            account_user_1_key,
+        ))
+        .expect_success()
+        .commit();
+
+    for account in [owner, account_user_1_key] {
+        builder
+            .exec(make_roles_request(ENTRY_POINT_REVOKE_ROLES, account))
+            .expect_failure();
+        assert_cep18_error(&builder, Cep18Error::AdminCountUnknown);
+    }
+
+    // once the count is given on upgrade, admins can be removed down to the last one
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NAME => TOKEN_NAME,
+            ARG_ADMIN_COUNT => 2u32,
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let cep18_token = get_contract_hash_v2_binary(&builder);
+    let make_revoke_request = |account: Key| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_token,
+            ENTRY_POINT_REVOKE_ROLES,
+            runtime_args! {ARG_ACCOUNT => account, ARG_ROLES => Roles::ADMIN.bits()},
+        )
+        .build()
+    };
+
+    builder
+        .exec(make_revoke_request(account_user_1_key))
+        .expect_success()
+        .commit();
+
+    builder.exec(make_revoke_request(owner)).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::CannotRemoveLastAdmin);
+
+    let admin_count: u32 = builder.get_value(
+        EntityAddr::SmartContract(cep18_token.value()),
+        ARG_ADMIN_COUNT,
+    );
+    assert_eq!(admin_count, 1);
+}
@@ -1,20 +1,26 @@
 use crate::utility::{
-    constants::{AMOUNT_1, AMOUNT_2, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY},
+    constants::{
+        AMOUNT_1, AMOUNT_2, AMOUNT_ALLOWANCE_1, AMOUNT_TRANSFER_1, TOKEN_DECIMALS, TOKEN_NAME,
+        TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
     installer_request_builders::{
-        cep18_check_balance_of, cep18_check_total_supply, get_test_account, setup_with_args,
-        TestContext,
+        cep18_check_allowance_of, cep18_check_balance_of, cep18_check_total_supply,
+        get_test_account, make_cep18_approve_request, setup_with_args, TestContext,
     },
 };
 use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
 use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
-use casper_types::{runtime_args, AddressableEntityHash, ApiError, Key, U256};
+use casper_types::{runtime_args, AddressableEntityHash, ApiError, EntityAddr, Key, U256};
 use cep18::{
     constants::{
-        ADMIN_LIST, ARG_AMOUNT, ARG_DECIMALS, ARG_ENABLE_MINT_BURN, ARG_NAME, ARG_OWNER,
-        ARG_SYMBOL, ARG_TOTAL_SUPPLY, ENTRY_POINT_BURN, ENTRY_POINT_CHANGE_SECURITY,
-        ENTRY_POINT_MINT, MINTER_LIST, NONE_LIST,
+        ADMIN_LIST, ARG_ACCOUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_ENABLE_MINT_BURN, ARG_MAX_SUPPLY,
+        ARG_MINTER, ARG_MINTER_ALLOWANCE, ARG_NAME, ARG_OWNER, ARG_ROLES, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, ENTRY_POINT_BURN, ENTRY_POINT_BURN_FROM, ENTRY_POINT_CHANGE_SECURITY,
+        ENTRY_POINT_GRANT_ROLES, ENTRY_POINT_MINT, ENTRY_POINT_SET_MINTER_ALLOWANCE, MINTER_LIST,
+        NONE_LIST,
     },
     error::Cep18Error,
+    security::Roles,
 };
 
 #[test]
@@ -199,6 +205,62 @@ fn test_should_not_mint_above_limits() {
     );
 }
 
+#[test]
+fn test_should_not_mint_above_max_supply() {
+    let max_supply = U256::from(TOKEN_TOTAL_SUPPLY + AMOUNT_1);
+
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_MINT_BURN => true,
+        ARG_MAX_SUPPLY => max_supply,
+    });
+
+    let stored_max_supply: U256 = builder.get_value(
+        EntityAddr::new_smart_contract(cep18_contract_hash.value()),
+        ARG_MAX_SUPPLY,
+    );
+    assert_eq!(stored_max_supply, max_supply);
+
+    let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
+    let addressable_cep18_contract_hash = AddressableEntityHash::new(cep18_contract_hash.value());
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        addressable_cep18_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {ARG_OWNER => account_user_1_key, ARG_AMOUNT => U256::from(AMOUNT_1)},
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_contract_hash),
+        max_supply
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        addressable_cep18_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {ARG_OWNER => account_user_1_key, ARG_AMOUNT => U256::one()},
+    )
+    .build();
+    builder.exec(mint_request).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::MaxSupplyExceeded as u16),
+        "Should not mint above max supply, but instead: {error:?}",
+    );
+}
+
 #[test]
 fn test_should_not_burn_above_balance() {
     let (
@@ -236,6 +298,94 @@ fn test_should_not_burn_above_balance() {
     );
 }
 
+#[test]
+fn test_burn_from_with_allowance() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_MINT_BURN => true,
+    });
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let (spender, spender_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    let allowance_amount = U256::from(AMOUNT_ALLOWANCE_1);
+    let burn_amount = U256::from(AMOUNT_TRANSFER_1);
+
+    let make_burn_from_request = |amount: U256| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            spender_account_hash,
+            cep18_contract_hash,
+            ENTRY_POINT_BURN_FROM,
+            runtime_args! {
+                ARG_OWNER => owner,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build()
+    };
+
+    let approve_request =
+        make_cep18_approve_request(owner, &cep18_contract_hash, spender, allowance_amount);
+    builder.exec(approve_request).expect_success().commit();
+
+    // an allowance alone does not let the spender burn
+    builder
+        .exec(make_burn_from_request(burn_amount))
+        .expect_failure();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::InsufficientRights as u16),
+        "{error:?}",
+    );
+
+    let grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_GRANT_ROLES,
+        runtime_args! {
+            ARG_ACCOUNT => spender,
+            ARG_ROLES => Roles::BURNER.bits(),
+        },
+    )
+    .build();
+    builder.exec(grant_request).expect_success().commit();
+
+    builder
+        .exec(make_burn_from_request(burn_amount))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, owner),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount
+    );
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_contract_hash),
+        U256::from(TOKEN_TOTAL_SUPPLY) - burn_amount
+    );
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount - burn_amount
+    );
+
+    builder
+        .exec(make_burn_from_request(allowance_amount))
+        .expect_failure();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::InsufficientAllowance as u16),
+        "{error:?}",
+    );
+}
+
 #[test]
 fn test_should_not_mint_or_burn_with_entrypoint_disabled() {
     let mint_amount = U256::one();
@@ -386,18 +536,125 @@ fn test_security_minter_rights() {
     });
 
     let addressable_cep18_contract_hash = AddressableEntityHash::new(cep18_contract_hash.value());
-    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
-        account_user_1_account_hash,
+    let make_mint_request = || {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            account_user_1_account_hash,
+            addressable_cep18_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_OWNER => account_user_1_key,
+                ARG_AMOUNT => mint_amount,
+            },
+        )
+        .build()
+    };
+
+    // minters cannot mint until an admin sets their minter allowance
+    builder.exec(make_mint_request()).expect_failure();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::MinterAllowanceExceeded as u16),
+        "{error:?}",
+    );
+
+    let set_minter_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
         addressable_cep18_contract_hash,
-        ENTRY_POINT_MINT,
+        ENTRY_POINT_SET_MINTER_ALLOWANCE,
         runtime_args! {
-            ARG_OWNER => account_user_1_key,
-            ARG_AMOUNT => mint_amount,
+            ARG_MINTER => account_user_1_key,
+            ARG_MINTER_ALLOWANCE => mint_amount,
         },
     )
     .build();
+    builder
+        .exec(set_minter_allowance_request)
+        .expect_success()
+        .commit();
+
+    builder.exec(make_mint_request()).commit().expect_success();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_1_key),
+        mint_amount
+    );
+}
+
+#[test]
+fn test_should_not_mint_above_minter_allowance() {
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    let minter_allowance = U256::from(AMOUNT_1);
 
-    builder.exec(mint_request).commit().expect_success();
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![account_user_1_key],
+    });
+
+    let set_minter_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_SET_MINTER_ALLOWANCE,
+        runtime_args! {
+            ARG_MINTER => account_user_1_key,
+            ARG_MINTER_ALLOWANCE => minter_allowance,
+        },
+    )
+    .build();
+    builder
+        .exec(set_minter_allowance_request)
+        .expect_success()
+        .commit();
+
+    let make_mint_request = |caller, amount: U256| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            caller,
+            cep18_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_OWNER => account_user_1_key,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .build()
+    };
+
+    builder
+        .exec(make_mint_request(
+            account_user_1_account_hash,
+            minter_allowance,
+        ))
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(make_mint_request(account_user_1_account_hash, U256::one()))
+        .expect_failure();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::MinterAllowanceExceeded as u16),
+        "{error:?}",
+    );
+
+    // admins are not limited by minter allowances
+    builder
+        .exec(make_mint_request(*DEFAULT_ACCOUNT_ADDR, U256::one()))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_1_key),
+        minter_allowance + U256::one()
+    );
 }
 
 #[test]
@@ -0,0 +1,182 @@
+use crate::utility::{
+    constants::{
+        AMOUNT_1, AMOUNT_TRANSFER_1, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY,
+    },
+    installer_request_builders::{
+        cep18_check_balance_of, get_test_account, make_cep18_transfer_request, setup_with_args,
+        TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{
+    account::AccountHash, runtime_args, AddressableEntityHash, ApiError, EntityAddr, Key, U256,
+};
+use cep18::{
+    constants::{
+        ARG_AMOUNT, ARG_DECIMALS, ARG_ENABLE_MINT_BURN, ARG_NAME, ARG_OWNER, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_MINT, ENTRY_POINT_PAUSE,
+        ENTRY_POINT_UNPAUSE, PAUSED, PAUSER_LIST,
+    },
+    error::Cep18Error,
+};
+
+fn setup_mint_and_burn() -> (LmdbWasmTestBuilder, TestContext) {
+    setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_MINT_BURN => true,
+    })
+}
+
+fn call_entry_point(
+    builder: &mut LmdbWasmTestBuilder,
+    caller: AccountHash,
+    cep18_contract_hash: AddressableEntityHash,
+    entry_point: &str,
+) -> bool {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep18_contract_hash,
+        entry_point,
+        runtime_args! {},
+    )
+    .build();
+    !builder.exec(request).commit().is_error()
+}
+
+fn assert_cep18_error(builder: &LmdbWasmTestBuilder, expected: Cep18Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected as u16),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn should_block_transfers_and_mints_while_paused() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_mint_and_burn();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
+    let transfer_amount = U256::from(AMOUNT_TRANSFER_1);
+
+    assert!(call_entry_point(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_PAUSE
+    ));
+    let paused: bool = builder.get_value(
+        EntityAddr::new_smart_contract(cep18_contract_hash.value()),
+        PAUSED,
+    );
+    assert!(paused);
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_contract_hash,
+        account_user_1_key,
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::ContractPaused);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {ARG_OWNER => account_user_1_key, ARG_AMOUNT => U256::from(AMOUNT_1)},
+    )
+    .build();
+    builder.exec(mint_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::ContractPaused);
+
+    assert!(call_entry_point(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_UNPAUSE
+    ));
+
+    let transfer_request = make_cep18_transfer_request(
+        owner,
+        &cep18_contract_hash,
+        account_user_1_key,
+        transfer_amount,
+    );
+    builder.exec(transfer_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_1_key),
+        transfer_amount
+    );
+}
+
+#[test]
+fn should_only_let_admins_and_pausers_pause() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_mint_and_burn();
+
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+
+    assert!(!call_entry_point(
+        &mut builder,
+        account_user_1_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_PAUSE
+    ));
+    assert_cep18_error(&builder, Cep18Error::InsufficientRights);
+
+    let change_security_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_CHANGE_SECURITY,
+        runtime_args! {
+            PAUSER_LIST => vec![account_user_1_key],
+        },
+    )
+    .build();
+    builder
+        .exec(change_security_request)
+        .expect_success()
+        .commit();
+
+    // A pauser can not mint.
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {ARG_OWNER => account_user_1_key, ARG_AMOUNT => U256::from(AMOUNT_1)},
+    )
+    .build();
+    builder.exec(mint_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InsufficientRights);
+
+    assert!(call_entry_point(
+        &mut builder,
+        account_user_1_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_PAUSE
+    ));
+    assert!(call_entry_point(
+        &mut builder,
+        account_user_1_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_UNPAUSE
+    ));
+}
@@ -0,0 +1,192 @@
+use crate::utility::{
+    constants::{AMOUNT_ALLOWANCE_1, AMOUNT_TRANSFER_1, CEP18_TEST_TOKEN_CONTRACT_PACKAGE_NAME},
+    installer_request_builders::{
+        cep18_check_allowance_of, cep18_check_balance_of, get_test_account, setup, TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequest, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_ACCOUNT_SECRET_KEY,
+};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    crypto, runtime_args, AddressableEntityHash, ApiError, Key, PublicKey, SecretKey, U256,
+};
+use cep18::{
+    constants::{
+        ARG_AMOUNT, ARG_DEADLINE, ARG_NONCE, ARG_OWNER, ARG_OWNER_PUBLIC_KEY, ARG_RECIPIENT,
+        ARG_SIGNATURE, ARG_SPENDER, ENTRY_POINT_PERMIT, ENTRY_POINT_TRANSFER_FROM,
+    },
+    error::Cep18Error,
+    permit::permit_message,
+};
+
+fn make_permit_request(
+    builder: &LmdbWasmTestBuilder,
+    cep18_contract_hash: AddressableEntityHash,
+    signer: (&SecretKey, &PublicKey),
+    spender: Key,
+    amount: U256,
+    nonce: u64,
+) -> ExecuteRequest {
+    let package_hash = *builder
+        .get_entity_with_named_keys_by_account_hash(*DEFAULT_ACCOUNT_ADDR)
+        .unwrap()
+        .named_keys()
+        .get(CEP18_TEST_TOKEN_CONTRACT_PACKAGE_NAME)
+        .expect("should have package hash");
+    let owner = Key::Account(DEFAULT_ACCOUNT_PUBLIC_KEY.to_account_hash());
+    let deadline = u64::MAX;
+    let message = permit_message(package_hash, owner, spender, amount, nonce, deadline);
+    let (secret_key, public_key) = signer;
+    let signature = crypto::sign(message, secret_key, public_key);
+
+    // Anyone can submit a permit; the spender does here.
+    let (_, spender_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    ExecuteRequestBuilder::contract_call_by_hash(
+        spender_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_PERMIT,
+        runtime_args! {
+            ARG_OWNER_PUBLIC_KEY => DEFAULT_ACCOUNT_PUBLIC_KEY.clone(),
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => amount,
+            ARG_NONCE => nonce,
+            ARG_DEADLINE => deadline,
+            ARG_SIGNATURE => Bytes::from(signature.to_bytes().unwrap()),
+        },
+    )
+    .build()
+}
+
+fn assert_cep18_error(builder: &LmdbWasmTestBuilder, expected: Cep18Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected as u16),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn should_approve_and_transfer_from_with_permit() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let (spender, spender_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    let allowance_amount = U256::from(AMOUNT_ALLOWANCE_1);
+
+    let permit_request = make_permit_request(
+        &builder,
+        cep18_contract_hash,
+        (&DEFAULT_ACCOUNT_SECRET_KEY, &DEFAULT_ACCOUNT_PUBLIC_KEY),
+        spender,
+        allowance_amount,
+        0,
+    );
+    builder.exec(permit_request).expect_success().commit();
+
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount
+    );
+
+    let transfer_amount = U256::from(AMOUNT_TRANSFER_1);
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        spender_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => spender,
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, spender),
+        transfer_amount
+    );
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        allowance_amount - transfer_amount
+    );
+}
+
+#[test]
+fn should_not_replay_permit() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let (spender, _, _) = get_test_account("ACCOUNT_USER_1");
+    let allowance_amount = U256::from(AMOUNT_ALLOWANCE_1);
+
+    let permit_request = make_permit_request(
+        &builder,
+        cep18_contract_hash,
+        (&DEFAULT_ACCOUNT_SECRET_KEY, &DEFAULT_ACCOUNT_PUBLIC_KEY),
+        spender,
+        allowance_amount,
+        0,
+    );
+    builder.exec(permit_request).expect_success().commit();
+
+    let replayed_permit_request = make_permit_request(
+        &builder,
+        cep18_contract_hash,
+        (&DEFAULT_ACCOUNT_SECRET_KEY, &DEFAULT_ACCOUNT_PUBLIC_KEY),
+        spender,
+        allowance_amount,
+        0,
+    );
+    builder.exec(replayed_permit_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InvalidPermitNonce);
+}
+
+#[test]
+fn should_not_permit_with_signature_of_other_key() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let (spender, _, _) = get_test_account("ACCOUNT_USER_1");
+    let other_secret_key = SecretKey::ed25519_from_bytes([42; 32]).unwrap();
+    let other_public_key = PublicKey::from(&other_secret_key);
+
+    let permit_request = make_permit_request(
+        &builder,
+        cep18_contract_hash,
+        (&other_secret_key, &other_public_key),
+        spender,
+        U256::from(AMOUNT_ALLOWANCE_1),
+        0,
+    );
+    builder.exec(permit_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InvalidPermitSignature);
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(
+        cep18_check_allowance_of(&mut builder, owner, spender),
+        U256::zero()
+    );
+}
@@ -0,0 +1,454 @@
+use crate::utility::{
+    constants::{AMOUNT_1, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOKEN_TOTAL_SUPPLY},
+    installer_request_builders::{
+        cep18_check_total_supply, get_test_account, make_cep18_approve_request, setup_with_args,
+        TestContext,
+    },
+};
+use casper_engine_test_support::{
+    ExecuteRequest, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecError};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes},
+    runtime_args, AddressableEntityHash, ApiError, EntityAddr, Key, U256,
+};
+use cep18::{
+    constants::{
+        ADMIN_LIST, ARG_ACCOUNT, ARG_ADMIN_COUNT, ARG_AMOUNT, ARG_DECIMALS, ARG_ENABLE_MINT_BURN,
+        ARG_MINTER, ARG_MINTER_ALLOWANCE, ARG_NAME, ARG_OWNER, ARG_ROLES, ARG_SYMBOL,
+        ARG_TOTAL_SUPPLY, ENTRY_POINT_BURN, ENTRY_POINT_BURN_FROM, ENTRY_POINT_CHANGE_SECURITY,
+        ENTRY_POINT_GRANT_ROLES, ENTRY_POINT_MINT, ENTRY_POINT_PAUSE, ENTRY_POINT_REVOKE_ROLES,
+        ENTRY_POINT_SET_MINTER_ALLOWANCE, ENTRY_POINT_UNPAUSE, MINTER_LIST, NONE_LIST, PAUSER_LIST,
+    },
+    error::Cep18Error,
+    security::{Roles, SecurityBadge},
+};
+
+fn setup_mint_and_burn() -> (LmdbWasmTestBuilder, TestContext) {
+    setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_MINT_BURN => true,
+    })
+}
+
+fn make_roles_request(
+    cep18_contract_hash: AddressableEntityHash,
+    caller: AccountHash,
+    entry_point: &str,
+    account: Key,
+    roles: u8,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep18_contract_hash,
+        entry_point,
+        runtime_args! {
+            ARG_ACCOUNT => account,
+            ARG_ROLES => roles,
+        },
+    )
+    .build()
+}
+
+fn make_mint_or_burn_request(
+    cep18_contract_hash: AddressableEntityHash,
+    caller: AccountHash,
+    entry_point: &str,
+    owner: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        cep18_contract_hash,
+        entry_point,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_AMOUNT => U256::from(AMOUNT_1),
+        },
+    )
+    .build()
+}
+
+fn make_set_minter_allowance_request(
+    cep18_contract_hash: AddressableEntityHash,
+    minter: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cep18_contract_hash,
+        ENTRY_POINT_SET_MINTER_ALLOWANCE,
+        runtime_args! {
+            ARG_MINTER => minter,
+            ARG_MINTER_ALLOWANCE => U256::from(AMOUNT_1),
+        },
+    )
+    .build()
+}
+
+fn get_admin_count(
+    builder: &LmdbWasmTestBuilder,
+    cep18_contract_hash: AddressableEntityHash,
+) -> u32 {
+    builder.get_value(
+        EntityAddr::new_smart_contract(cep18_contract_hash.value()),
+        ARG_ADMIN_COUNT,
+    )
+}
+
+fn assert_cep18_error(builder: &LmdbWasmTestBuilder, expected: Cep18Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected as u16),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn should_hold_several_roles() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_mint_and_burn();
+
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+
+    let grant_request = make_roles_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        ENTRY_POINT_GRANT_ROLES,
+        account_user_1_key,
+        (Roles::MINTER | Roles::PAUSER).bits(),
+    );
+    builder.exec(grant_request).expect_success().commit();
+
+    for entry_point in [ENTRY_POINT_PAUSE, ENTRY_POINT_UNPAUSE] {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            account_user_1_account_hash,
+            cep18_contract_hash,
+            entry_point,
+            runtime_args! {},
+        )
+        .build();
+        builder.exec(request).expect_success().commit();
+    }
+    let allowance_request =
+        make_set_minter_allowance_request(cep18_contract_hash, account_user_1_key);
+    builder.exec(allowance_request).expect_success().commit();
+    let mint_request = make_mint_or_burn_request(
+        cep18_contract_hash,
+        account_user_1_account_hash,
+        ENTRY_POINT_MINT,
+        account_user_1_key,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let revoke_request = make_roles_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        ENTRY_POINT_REVOKE_ROLES,
+        account_user_1_key,
+        Roles::MINTER.bits(),
+    );
+    builder.exec(revoke_request).expect_success().commit();
+
+    let mint_request = make_mint_or_burn_request(
+        cep18_contract_hash,
+        account_user_1_account_hash,
+        ENTRY_POINT_MINT,
+        account_user_1_key,
+    );
+    builder.exec(mint_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InsufficientRights);
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+}
+
+#[test]
+fn should_not_let_burners_burn_tokens_of_other_accounts() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_mint_and_burn();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+
+    let grant_request = make_roles_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        ENTRY_POINT_GRANT_ROLES,
+        account_user_1_key,
+        Roles::BURNER.bits(),
+    );
+    builder.exec(grant_request).expect_success().commit();
+
+    let burn_request = make_mint_or_burn_request(
+        cep18_contract_hash,
+        account_user_1_account_hash,
+        ENTRY_POINT_BURN,
+        owner,
+    );
+    builder.exec(burn_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InvalidBurnTarget);
+
+    // Burning the tokens of another account takes an allowance from it.
+    let approve_request = make_cep18_approve_request(
+        owner,
+        &cep18_contract_hash,
+        account_user_1_key,
+        U256::from(AMOUNT_1),
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    let burn_from_request = make_mint_or_burn_request(
+        cep18_contract_hash,
+        account_user_1_account_hash,
+        ENTRY_POINT_BURN_FROM,
+        owner,
+    );
+    builder.exec(burn_from_request).expect_success().commit();
+    assert_eq!(
+        cep18_check_total_supply(&mut builder, &cep18_contract_hash),
+        U256::from(TOKEN_TOTAL_SUPPLY - AMOUNT_1)
+    );
+}
+
+#[test]
+fn should_add_up_roles_given_at_install() {
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    let (account_user_2_key, ..) = get_test_account("ACCOUNT_USER_2");
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_TOTAL_SUPPLY => U256::from(TOKEN_TOTAL_SUPPLY),
+        ARG_ENABLE_MINT_BURN => true,
+        MINTER_LIST => vec![owner, account_user_1_key],
+        PAUSER_LIST => vec![account_user_1_key],
+    });
+
+    // The installer listed as minter stays an Admin.
+    assert_eq!(get_admin_count(&builder, cep18_contract_hash), 1);
+    let grant_request = make_roles_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        ENTRY_POINT_GRANT_ROLES,
+        account_user_2_key,
+        Roles::ADMIN.bits(),
+    );
+    builder.exec(grant_request).expect_success().commit();
+
+    // An account listed as minter and pauser holds both roles.
+    let allowance_request =
+        make_set_minter_allowance_request(cep18_contract_hash, account_user_1_key);
+    builder.exec(allowance_request).expect_success().commit();
+    let mint_request = make_mint_or_burn_request(
+        cep18_contract_hash,
+        account_user_1_account_hash,
+        ENTRY_POINT_MINT,
+        account_user_1_key,
+    );
+    builder.exec(mint_request).expect_success().commit();
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1_account_hash,
+        cep18_contract_hash,
+        ENTRY_POINT_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+}
+
+#[test]
+fn should_only_let_admins_grant_known_roles() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_mint_and_burn();
+
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+
+    let grant_request = make_roles_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        ENTRY_POINT_GRANT_ROLES,
+        account_user_1_key,
+        1 << 4,
+    );
+    builder.exec(grant_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InvalidRoles);
+
+    let grant_request = make_roles_request(
+        cep18_contract_hash,
+        account_user_1_account_hash,
+        ENTRY_POINT_GRANT_ROLES,
+        account_user_1_key,
+        Roles::ADMIN.bits(),
+    );
+    builder.exec(grant_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::InsufficientRights);
+}
+
+#[test]
+fn should_not_remove_last_admin() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup_mint_and_burn();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    assert_eq!(get_admin_count(&builder, cep18_contract_hash), 1);
+
+    let revoke_request = make_roles_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        ENTRY_POINT_REVOKE_ROLES,
+        owner,
+        Roles::ADMIN.bits(),
+    );
+    builder.exec(revoke_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::CannotRemoveLastAdmin);
+
+    let grant_request = make_roles_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        ENTRY_POINT_GRANT_ROLES,
+        account_user_1_key,
+        Roles::ADMIN.bits(),
+    );
+    builder.exec(grant_request).expect_success().commit();
+    assert_eq!(get_admin_count(&builder, cep18_contract_hash), 2);
+
+    let revoke_request = make_roles_request(
+        cep18_contract_hash,
+        *DEFAULT_ACCOUNT_ADDR,
+        ENTRY_POINT_REVOKE_ROLES,
+        owner,
+        Roles::ADMIN.bits(),
+    );
+    builder.exec(revoke_request).expect_success().commit();
+    assert_eq!(get_admin_count(&builder, cep18_contract_hash), 1);
+
+    let revoke_request = make_roles_request(
+        cep18_contract_hash,
+        account_user_1_account_hash,
+        ENTRY_POINT_REVOKE_ROLES,
+        account_user_1_key,
+        Roles::ADMIN.bits(),
+    );
+    builder.exec(revoke_request).expect_failure();
+    assert_cep18_error(&builder, Cep18Error::CannotRemoveLastAdmin);
+}
+
+#[test]
+fn should_hand_admin_role_over_in_one_call_in_any_key_order() {
+    let (account_user_1_key, account_user_1_account_hash, _) = get_test_account("ACCOUNT_USER_1");
+    let (account_user_2_key, account_user_2_account_hash, _) = get_test_account("ACCOUNT_USER_2");
+    let users = [
+        (account_user_1_key, account_user_1_account_hash),
+        (account_user_2_key, account_user_2_account_hash),
+    ];
+
+    // the demoted Admin sorts first in one run and last in the other
+    for (old_admin, new_admin) in [(users[0], users[1]), (users[1], users[0])] {
+        let (
+            mut builder,
+            TestContext {
+                cep18_contract_hash,
+                ..
+            },
+        ) = setup_mint_and_burn();
+
+        let grant_request = make_roles_request(
+            cep18_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            ENTRY_POINT_GRANT_ROLES,
+            old_admin.0,
+            Roles::ADMIN.bits(),
+        );
+        builder.exec(grant_request).expect_success().commit();
+        assert_eq!(get_admin_count(&builder, cep18_contract_hash), 2);
+
+        let handover_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            cep18_contract_hash,
+            ENTRY_POINT_CHANGE_SECURITY,
+            runtime_args! {
+                ADMIN_LIST => vec![new_admin.0],
+                NONE_LIST => vec![old_admin.0],
+            },
+        )
+        .build();
+        builder.exec(handover_request).expect_success().commit();
+        assert_eq!(get_admin_count(&builder, cep18_contract_hash), 2);
+
+        // only the new Admin can still grant roles
+        let grant_request = make_roles_request(
+            cep18_contract_hash,
+            new_admin.1,
+            ENTRY_POINT_GRANT_ROLES,
+            new_admin.0,
+            Roles::PAUSER.bits(),
+        );
+        builder.exec(grant_request).expect_success().commit();
+
+        let grant_request = make_roles_request(
+            cep18_contract_hash,
+            old_admin.1,
+            ENTRY_POINT_GRANT_ROLES,
+            old_admin.0,
+            Roles::ADMIN.bits(),
+        );
+        builder.exec(grant_request).expect_failure();
+        assert_cep18_error(&builder, Cep18Error::InsufficientRights);
+    }
+}
+
+#[test]
+fn should_deserialize_security_badge_with_trailing_bytes() {
+    let (badge, remainder) = SecurityBadge::from_bytes(&[1, 7]).unwrap();
+    assert_eq!(badge, SecurityBadge::Minter);
+    assert_eq!(remainder, &[7]);
+
+    assert_eq!(
+        SecurityBadge::from_bytes(&[4]).unwrap_err(),
+        bytesrepr::Error::Formatting
+    );
+    assert_eq!(
+        SecurityBadge::from_bytes(&[]).unwrap_err(),
+        bytesrepr::Error::EarlyEndOfStream
+    );
+}
@@ -1,5 +1,5 @@
 use crate::utility::{
-    constants::{AMOUNT_ALLOWANCE_1, AMOUNT_TRANSFER_1, TOKEN_TOTAL_SUPPLY},
+    constants::{AMOUNT_ALLOWANCE_1, AMOUNT_TRANSFER_1, AMOUNT_TRANSFER_2, TOKEN_TOTAL_SUPPLY},
     installer_request_builders::{
         cep18_check_allowance_of, cep18_check_balance_of, get_test_account,
         make_cep18_approve_request, make_cep18_transfer_request, setup, test_cep18_transfer,
@@ -11,8 +11,9 @@ use casper_execution_engine::{engine_state::Error as CoreError, execution::ExecE
 use casper_types::{runtime_args, AddressableEntityHash, ApiError, EntityAddr, Key, U256};
 use cep18::{
     constants::{
-        ARG_AMOUNT, ARG_OWNER, ARG_RECIPIENT, ARG_SPENDER, ARG_TOTAL_SUPPLY, ENTRY_POINT_APPROVE,
-        ENTRY_POINT_TRANSFER, ENTRY_POINT_TRANSFER_FROM,
+        ARG_AMOUNT, ARG_OWNER, ARG_RECIPIENT, ARG_RECIPIENTS, ARG_SPENDER, ARG_TOTAL_SUPPLY,
+        ENTRY_POINT_APPROVE, ENTRY_POINT_BATCH_TRANSFER, ENTRY_POINT_TRANSFER,
+        ENTRY_POINT_TRANSFER_FROM,
     },
     error::Cep18Error,
 };
@@ -601,3 +602,141 @@ fn should_transfer_account_to_account() {
         recipient2,
     );
 }
+
+#[test]
+fn should_batch_transfer_to_several_recipients() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let (sender_key, sender_account_hash, _) = get_test_account("ACCOUNT_USER_0");
+    let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
+    let (account_user_2_key, _, _) = get_test_account("ACCOUNT_USER_2");
+
+    let transfer_amount_1 = U256::from(AMOUNT_TRANSFER_1);
+    let transfer_amount_2 = U256::from(AMOUNT_TRANSFER_2);
+    let recipients = vec![
+        (account_user_1_key, transfer_amount_1),
+        (account_user_2_key, transfer_amount_2),
+        (account_user_1_key, transfer_amount_2),
+    ];
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender_account_hash,
+        AddressableEntityHash::new(cep18_contract_hash.value()),
+        ENTRY_POINT_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => recipients,
+        },
+    )
+    .build();
+    builder
+        .exec(batch_transfer_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_1_key),
+        transfer_amount_1 + transfer_amount_2
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_2_key),
+        transfer_amount_2
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, sender_key),
+        U256::from(TOKEN_TOTAL_SUPPLY) - transfer_amount_1 - transfer_amount_2 * 2
+    );
+}
+
+#[test]
+fn should_not_batch_transfer_more_than_owned_balance() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let (sender_key, sender_account_hash, _) = get_test_account("ACCOUNT_USER_0");
+    let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
+    let (account_user_2_key, _, _) = get_test_account("ACCOUNT_USER_2");
+
+    // Each transfer fits the balance on its own, both together do not.
+    let initial_supply = U256::from(TOKEN_TOTAL_SUPPLY);
+    let recipients = vec![
+        (account_user_1_key, initial_supply),
+        (account_user_2_key, U256::one()),
+    ];
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender_account_hash,
+        AddressableEntityHash::new(cep18_contract_hash.value()),
+        ENTRY_POINT_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => recipients,
+        },
+    )
+    .build();
+    builder.exec(batch_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::InsufficientBalance as u16),
+        "{error:?}",
+    );
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_1_key),
+        U256::zero()
+    );
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, sender_key),
+        initial_supply
+    );
+}
+
+#[test]
+fn should_not_batch_transfer_to_self() {
+    let (
+        mut builder,
+        TestContext {
+            cep18_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let (sender_key, sender_account_hash, _) = get_test_account("ACCOUNT_USER_0");
+    let (account_user_1_key, _, _) = get_test_account("ACCOUNT_USER_1");
+    let transfer_amount = U256::from(AMOUNT_TRANSFER_1);
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender_account_hash,
+        AddressableEntityHash::new(cep18_contract_hash.value()),
+        ENTRY_POINT_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => vec![
+                (account_user_1_key, transfer_amount),
+                (sender_key, transfer_amount),
+            ],
+        },
+    )
+    .build();
+    builder.exec(batch_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == Cep18Error::CannotTargetSelfUser as u16),
+        "{error:?}",
+    );
+
+    assert_eq!(
+        cep18_check_balance_of(&mut builder, &cep18_contract_hash, account_user_1_key),
+        U256::zero()
+    );
+}
@@ -3,6 +3,7 @@ pub const CEP18_TEST_CONTRACT_WASM: &str = "cep18_test_contract.wasm";
 
 pub const CEP18_TEST_TOKEN_CONTRACT_NAME: &str = "cep18_contract_hash_CasperTest";
 pub const CEP18_TEST_TOKEN_CONTRACT_VERSION: &str = "cep18_contract_version_CasperTest";
+pub const CEP18_TEST_TOKEN_CONTRACT_PACKAGE_NAME: &str = "cep18_contract_package_CasperTest";
 
 pub const TOKEN_NAME: &str = "CasperTest";
 pub const TOKEN_SYMBOL: &str = "CSPRT";
@@ -0,0 +1,206 @@
+//! Multi-hop router for the AMM factory pools
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{
+    contracts::ContractHash, runtime_args, ApiError, CLType, CLTyped, CLValue,
+    EntityEntryPoint as EntryPoint, EntryPointAccess, EntryPointPayment, EntryPointType,
+    EntryPoints, Key, NamedKeys, Parameter, U256,
+};
+
+const FACTORY: &str = "factory";
+const PACKAGE_HASH: &str = "package_hash";
+
+const ARG_FACTORY: &str = "factory";
+const ARG_PACKAGE_HASH: &str = "package_hash";
+const ARG_PATH: &str = "path";
+const ARG_AMOUNT_IN: &str = "amount_in";
+const ARG_MIN_OUT: &str = "min_out";
+const ARG_DEADLINE: &str = "deadline";
+
+const PACKAGE_KEY_NAME: &str = "simple_amm_router_package";
+
+// Error codes
+const ERROR_INVALID_PATH: u16 = 1;
+const ERROR_POOL_NOT_FOUND: u16 = 2;
+const ERROR_INSUFFICIENT_OUTPUT: u16 = 3;
+const ERROR_EXPIRED: u16 = 4;
+const ERROR_ALREADY_INITIALIZED: u16 = 5;
+const ERROR_INVALID_KEY: u16 = 6;
+
+fn read_key(name: &str) -> Key {
+    let uref = runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+fn to_contract_hash(key: Key) -> ContractHash {
+    key.into_hash_addr()
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(ApiError::User(ERROR_INVALID_KEY))
+}
+
+/// Finds the pool for a hop and returns its contract hash, its package key (the spender the
+/// token sees when the pool pulls input) and whether the hop swaps the pool's token A for B.
+fn resolve_hop(factory: ContractHash, token_in: Key, token_out: Key) -> (ContractHash, Key, bool) {
+    let pool: Option<Key> = runtime::call_contract(
+        factory,
+        "get_pool",
+        runtime_args! {
+            "token_x" => token_in,
+            "token_y" => token_out,
+        },
+    );
+    let pool = to_contract_hash(pool.unwrap_or_revert_with(ApiError::User(ERROR_POOL_NOT_FOUND)));
+
+    let (token_a, _, pool_package): (Key, Key, Key) =
+        runtime::call_contract(pool, "get_pool_info", runtime_args! {});
+
+    (pool, pool_package, token_a == token_in)
+}
+
+/// Checks the deadline and the path length shared by every swap entry point.
+fn validate_swap(path: &[Key], deadline: u64) {
+    if u64::from(runtime::get_blocktime()) > deadline {
+        runtime::revert(ApiError::User(ERROR_EXPIRED));
+    }
+    if path.len() < 2 {
+        runtime::revert(ApiError::User(ERROR_INVALID_PATH));
+    }
+}
+
+/// Swaps `amount_in` of `path[0]`, already held by the router, through one pool per consecutive
+/// pair of `path`. Intermediate hops pay the router; the last hop pays `recipient`. Returns the
+/// final output after checking it against `min_out`.
+fn swap_along(path: &[Key], amount_in: U256, min_out: U256, recipient: Key) -> U256 {
+    let factory = to_contract_hash(read_key(FACTORY));
+    let router = read_key(PACKAGE_HASH);
+
+    let last_hop = path.len() - 2;
+    let mut amount = amount_in;
+    for (hop, pair) in path.windows(2).enumerate() {
+        let (token_in, token_out) = (pair[0], pair[1]);
+        let (pool, pool_package, a_to_b) = resolve_hop(factory, token_in, token_out);
+        let hop_recipient = if hop == last_hop { recipient } else { router };
+
+        runtime::call_contract::<()>(
+            to_contract_hash(token_in),
+            "approve",
+            runtime_args! {
+                "spender" => pool_package,
+                "amount" => amount,
+            },
+        );
+
+        amount = runtime::call_contract(
+            pool,
+            if a_to_b { "swap_a_to_b" } else { "swap_b_to_a" },
+            runtime_args! {
+                "amount_in" => amount,
+                "min_amount_out" => U256::zero(),
+                "recipient" => hop_recipient,
+            },
+        );
+    }
+
+    if amount < min_out {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_OUTPUT));
+    }
+    amount
+}
+
+/// Stores the router's package key as `Key::Hash`, the form tokens see when the router calls
+/// them, so balances the router receives and spends are keyed the same way.
+#[no_mangle]
+pub extern "C" fn init() {
+    if runtime::has_key(PACKAGE_HASH) {
+        runtime::revert(ApiError::User(ERROR_ALREADY_INITIALIZED));
+    }
+    let package_hash = match runtime::get_named_arg::<Key>(ARG_PACKAGE_HASH) {
+        Key::Hash(package_hash) | Key::SmartContract(package_hash) => Key::Hash(package_hash),
+        _ => runtime::revert(ApiError::User(ERROR_INVALID_KEY)),
+    };
+    runtime::put_key(PACKAGE_HASH, storage::new_uref(package_hash).into());
+}
+
+/// Swaps an exact `amount_in` of `path[0]` along `path`, one pool per consecutive pair, and sends
+/// the final output to the caller. Reverts the whole path if the output is below `min_out`.
+#[no_mangle]
+pub extern "C" fn swap_exact_tokens_for_tokens() {
+    let path: Vec<Key> = runtime::get_named_arg(ARG_PATH);
+    let amount_in: U256 = runtime::get_named_arg(ARG_AMOUNT_IN);
+    let min_out: U256 = runtime::get_named_arg(ARG_MIN_OUT);
+    let deadline: u64 = runtime::get_named_arg(ARG_DEADLINE);
+
+    validate_swap(&path, deadline);
+
+    let caller = Key::from(runtime::get_caller());
+    runtime::call_contract::<()>(
+        to_contract_hash(path[0]),
+        "transfer_from",
+        runtime_args! {
+            "owner" => caller,
+            "recipient" => read_key(PACKAGE_HASH),
+            "amount" => amount_in,
+        },
+    );
+
+    let amount_out = swap_along(&path, amount_in, min_out, caller);
+    runtime::ret(CLValue::from_t(amount_out).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let factory: Key = runtime::get_named_arg(ARG_FACTORY);
+
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("init"),
+        vec![Parameter::new(ARG_PACKAGE_HASH, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from("swap_exact_tokens_for_tokens"),
+        vec![
+            Parameter::new(ARG_PATH, CLType::List(Box::new(Key::cl_type()))),
+            Parameter::new(ARG_AMOUNT_IN, U256::cl_type()),
+            Parameter::new(ARG_MIN_OUT, U256::cl_type()),
+            Parameter::new(ARG_DEADLINE, CLType::U64),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from(FACTORY), storage::new_uref(factory).into());
+
+    let (contract_hash, _) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(String::from(PACKAGE_KEY_NAME)),
+        None,
+        None,
+    );
+    runtime::put_key("simple_amm_router", contract_hash.into());
+
+    let package_hash = runtime::get_key(PACKAGE_KEY_NAME).unwrap_or_revert();
+    runtime::call_contract::<()>(
+        contract_hash,
+        "init",
+        runtime_args! { ARG_PACKAGE_HASH => package_hash },
+    );
+}
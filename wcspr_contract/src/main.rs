@@ -0,0 +1,298 @@
+//! Wrapped CSPR: a CEP-18 token backed 1:1 by native CSPR held in the contract purse, so CSPR can
+//! be traded through the CEP-18 based AMM pools.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::ToString, vec};
+use casper_contract::{
+    contract_api::{
+        runtime::{self, get_key, get_named_arg, put_key, revert},
+        storage, system,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contract_messages::MessageTopicOperation, runtime_args, ApiError, CLType, CLTyped,
+    EntityEntryPoint as EntryPoint, EntryPointAccess, EntryPointPayment, EntryPointType,
+    EntryPoints, Key, NamedKeys, Parameter, URef, U256, U512,
+};
+use cep18::{
+    balances::{read_balance_from, write_balance_to},
+    constants::{
+        ARG_AMOUNT, ARG_CONTRACT_HASH, ARG_DECIMALS, ARG_EVENTS, ARG_EVENTS_MODE, ARG_NAME,
+        ARG_PACKAGE_HASH, ARG_SYMBOL, ARG_TOTAL_SUPPLY, DICT_ALLOWANCES, DICT_BALANCES,
+        ENTRY_POINT_INIT,
+    },
+    entry_points,
+    error::Cep18Error,
+    events::{self, init_events, Burn, Event, Mint},
+    token,
+    utils::{
+        get_immediate_caller, get_optional_named_arg_with_user_errors, get_stored_value,
+        write_total_supply_to,
+    },
+};
+
+const TOKEN_NAME: &str = "Wrapped CSPR";
+const TOKEN_SYMBOL: &str = "WCSPR";
+/// Same precision as CSPR itself: one token unit is one mote.
+const TOKEN_DECIMALS: u8 = 9;
+
+const PURSE: &str = "purse";
+const ARG_PURSE: &str = "purse";
+
+const ENTRY_POINT_DEPOSIT: &str = "deposit";
+const ENTRY_POINT_WITHDRAW: &str = "withdraw";
+
+const PACKAGE_KEY_NAME: &str = "wcspr_contract_package";
+const ACCESS_KEY_NAME: &str = "wcspr_contract_package_access";
+const CONTRACT_KEY_NAME: &str = "wcspr_contract_hash";
+
+// Error codes
+const ERROR_ZERO_AMOUNT: u16 = 1;
+
+fn contract_purse() -> URef {
+    get_key(PURSE)
+        .unwrap_or_revert_with(Cep18Error::MissingStorageUref)
+        .into_uref()
+        .unwrap_or_revert_with(Cep18Error::InvalidStorageUref)
+}
+
+/// Token units are motes, so every token amount fits in a `U512` mote amount.
+fn to_motes(amount: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    amount.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+#[no_mangle]
+pub extern "C" fn name() {
+    token::name();
+}
+
+#[no_mangle]
+pub extern "C" fn symbol() {
+    token::symbol();
+}
+
+#[no_mangle]
+pub extern "C" fn decimals() {
+    token::decimals();
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    token::total_supply();
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    token::balance_of();
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    token::allowance();
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    token::approve();
+}
+
+#[no_mangle]
+pub extern "C" fn decrease_allowance() {
+    token::decrease_allowance();
+}
+
+#[no_mangle]
+pub extern "C" fn increase_allowance() {
+    token::increase_allowance();
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    token::transfer();
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    token::transfer_from();
+}
+
+/// Moves `amount` motes from the given purse into the contract purse and mints the same amount
+/// of WCSPR to the caller.
+#[no_mangle]
+pub extern "C" fn deposit() {
+    let purse: URef = runtime::get_named_arg(ARG_PURSE);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    if amount.is_zero() {
+        revert(ApiError::User(ERROR_ZERO_AMOUNT));
+    }
+
+    system::transfer_from_purse_to_purse(purse, contract_purse(), to_motes(amount), None)
+        .unwrap_or_revert();
+
+    let owner = get_immediate_caller();
+    let new_balance = read_balance_from(owner)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Cep18Error::Overflow);
+    let new_total_supply = get_stored_value::<U256>(ARG_TOTAL_SUPPLY)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Cep18Error::Overflow);
+
+    write_balance_to(owner, new_balance);
+    write_total_supply_to(new_total_supply);
+
+    events::record_event_dictionary(Event::Mint(Mint {
+        recipient: owner,
+        amount,
+    }));
+}
+
+/// Burns `amount` WCSPR from the caller and pays the same amount of motes from the contract purse
+/// into the given purse.
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let purse: URef = runtime::get_named_arg(ARG_PURSE);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    if amount.is_zero() {
+        revert(ApiError::User(ERROR_ZERO_AMOUNT));
+    }
+
+    let owner = get_immediate_caller();
+    let new_balance = read_balance_from(owner)
+        .checked_sub(amount)
+        .unwrap_or_revert_with(Cep18Error::InsufficientBalance);
+    let new_total_supply = get_stored_value::<U256>(ARG_TOTAL_SUPPLY)
+        .checked_sub(amount)
+        .unwrap_or_revert_with(Cep18Error::FailedToChangeTotalSupply);
+
+    write_balance_to(owner, new_balance);
+    write_total_supply_to(new_total_supply);
+
+    system::transfer_from_purse_to_purse(contract_purse(), purse, to_motes(amount), None)
+        .unwrap_or_revert();
+
+    events::record_event_dictionary(Event::Burn(Burn { owner, amount }));
+}
+
+/// Initiates the contract state. Only used by the installer call, later calls will cause it to
+/// revert.
+#[no_mangle]
+pub extern "C" fn init() {
+    if get_key(DICT_ALLOWANCES).is_some() {
+        revert(Cep18Error::AlreadyInitialized);
+    }
+    put_key(ARG_PACKAGE_HASH, get_named_arg::<Key>(ARG_PACKAGE_HASH));
+    put_key(ARG_CONTRACT_HASH, get_named_arg::<Key>(ARG_CONTRACT_HASH));
+
+    storage::new_dictionary(DICT_ALLOWANCES)
+        .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_BALANCES)
+        .unwrap_or_revert_with(Cep18Error::FailedToCreateDictionary);
+    put_key(PURSE, system::create_purse().into());
+
+    init_events();
+}
+
+fn deposit_entry_point() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_DEPOSIT.to_string(),
+        vec![
+            Parameter::new(ARG_PURSE, URef::cl_type()),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    )
+}
+
+fn withdraw_entry_point() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_WITHDRAW.to_string(),
+        vec![
+            Parameter::new(ARG_PURSE, URef::cl_type()),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    )
+}
+
+/// The CEP-18 entry points without minting, burning or security management: supply only changes
+/// through `deposit` and `withdraw`.
+fn generate_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(entry_points::init());
+    entry_points.add_entry_point(entry_points::name());
+    entry_points.add_entry_point(entry_points::symbol());
+    entry_points.add_entry_point(entry_points::decimals());
+    entry_points.add_entry_point(entry_points::total_supply());
+    entry_points.add_entry_point(entry_points::balance_of());
+    entry_points.add_entry_point(entry_points::transfer());
+    entry_points.add_entry_point(entry_points::approve());
+    entry_points.add_entry_point(entry_points::allowance());
+    entry_points.add_entry_point(entry_points::decrease_allowance());
+    entry_points.add_entry_point(entry_points::increase_allowance());
+    entry_points.add_entry_point(entry_points::transfer_from());
+    entry_points.add_entry_point(deposit_entry_point());
+    entry_points.add_entry_point(withdraw_entry_point());
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let events_mode: u8 =
+        get_optional_named_arg_with_user_errors(ARG_EVENTS_MODE, Cep18Error::InvalidEventsMode)
+            .unwrap_or(0u8);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(ARG_NAME.to_string(), storage::new_uref(TOKEN_NAME).into());
+    named_keys.insert(
+        ARG_SYMBOL.to_string(),
+        storage::new_uref(TOKEN_SYMBOL).into(),
+    );
+    named_keys.insert(
+        ARG_DECIMALS.to_string(),
+        storage::new_uref(TOKEN_DECIMALS).into(),
+    );
+    named_keys.insert(
+        ARG_TOTAL_SUPPLY.to_string(),
+        storage::new_uref(U256::zero()).into(),
+    );
+    named_keys.insert(
+        ARG_EVENTS_MODE.to_string(),
+        storage::new_uref(events_mode).into(),
+    );
+
+    let message_topics = BTreeMap::from([(ARG_EVENTS.to_string(), MessageTopicOperation::Add)]);
+
+    let (contract_hash, _) = storage::new_contract(
+        generate_entry_points(),
+        Some(named_keys),
+        Some(PACKAGE_KEY_NAME.to_string()),
+        Some(ACCESS_KEY_NAME.to_string()),
+        Some(message_topics),
+    );
+
+    let package_hash =
+        get_key(PACKAGE_KEY_NAME).unwrap_or_revert_with(Cep18Error::FailedToGetPackageKey);
+    let contract_hash_key = Key::Hash(contract_hash.value());
+    put_key(CONTRACT_KEY_NAME, contract_hash_key);
+
+    runtime::call_contract::<()>(
+        contract_hash,
+        ENTRY_POINT_INIT,
+        runtime_args! {
+            ARG_PACKAGE_HASH => package_hash,
+            ARG_CONTRACT_HASH => contract_hash_key,
+        },
+    );
+}